@@ -11,6 +11,8 @@ names = names
 names_description = show names for fatty acids
 properties = properties
 properties_description = show { properties }
+reference = reference
+relative = relative sn-2
 resize = resize table columns
 selectivity_factor = selectivity factor
 species = species
@@ -83,6 +85,8 @@ from_mag = from 2-{ monoacylglycerol.abbreviation }s
 
 ## Composition
 adduct = adduct
+average_mass = average { mass }
+monoisotopic_mass = monoisotopic { mass }
 method = method
 gunstone = Gunstone
     .description = calculate by { gunstone }'s theory
@@ -100,7 +104,121 @@ ascending = ascending
 descending = descending
     .description = reverse order (from max to min)
 
+## Comparison
+baseline = baseline
+comparison = comparison
+comparison_description = select two or more samples in the settings, the first one is the baseline
+unmatched = unmatched fatty acids
+
+## Contribution
+contribution = score contribution
+
+## Reference
+no_reference = no reference
+out_of_range = out of reference range
+reference_range = reference range
+
+## Radar
+radar = radar chart
+    .description = major fatty acids normalized to the reference median, select samples in the settings
+threshold = threshold
+    .description = minimal reference median (SN123) of a major fatty acid
+
+## Goal seek
+goal_seek = goal seek
+goal = goal
+zero_deviation = zero deviation
+    .description = every value inside the reference range
+gain = gain
+    .description = score gained by fixing the value alone (E)
+cumulative_score = score after the adjustments above
+target = target
+    .description = value needed to reach the goal, nearest bound of the reference range or a partial adjustment
+
+## Sensitivity
+sensitivity = sensitivity
+perturbation = perturbation
+    .description = every input value is changed by ±x % one at a time, the change of the score (100 - ∑F) is recorded
+
+## Explain
+explain = explain
+    .description = worked derivation of A-F for a row or of the scores for the totals
+copy = copy
+not_scored = not in the reference, not scored
+
+## Import
+import = import
+    .description = import a CSV, TSV or spreadsheet (XLSX, XLS, ODS) file, drop it on the window or paste its content
+decimal_comma = decimal comma
+    .description = numbers use a comma as the decimal separator
+delimiter = delimiter
+header = header
+    .description = the first line holds the column names
+invalid_rows = invalid rows are skipped
+name = name
+range = range
+    .description = cell range of every selected sheet, e.g. A1:C40, the used range if empty
+sheets = sheets
+    .description = every checked sheet becomes its own pane, click a name to preview it
+source = source
+
+## Export
+export = export
+    .description = export the calculated table: index, fatty acid, experimental values, A-E of both positions, F and the totals; Parquet keeps the nested columns and the metadata, AsciiDoc and Markdown tables keep the grouped header
+export_ranking = export ranking
+    .description = export the ranking table as AsciiDoc or Markdown with the grouped header, numbers follow the precision
+
+## Paste
+clipboard = clipboard
+line = line
+pasted_rows = pasted rows
+
+## Selection
+selection = selection
+    .description = click a cell, shift click or drag to select a range, Ctrl+C copies it with the header as tab separated text
+
+## Workbook
+workbook = workbook
+    .description = export every calculation pane to its own Excel (xlsx) sheet with the metadata, input, A-F and totals, and rank them on a summary sheet
+no_calculation_panes = there are no calculation panes
+rank = rank
+version = version
+
+## Save
+save = save
+    .description = IPC is compact, RON and JSON are human readable and diffable, all of them keep the metadata and open when dropped on the window
+
+## Report
+report = report
+    .description = self-contained HTML report: metadata, input, A-F, totals, charts and the reference
+charts = charts
+description = description
+input = input
+metadata = metadata
+totals = totals
+
+## Validation
+problems = problems
+    .description = checks of the input on load and edit: negative and not a number values, duplicate fatty acids, sums of SN123 and SN2 far from 100, relative sn-2 above 100 %, SN2 without SN123 and double bond indices beyond the chain length; click a row to select its cell in the table
+duplicate_of_row = duplicate of row
+index_beyond_chain = double bond index beyond the chain length
+negative_value = negative value
+not_a_number = not a number
+relative_above_100 = relative sn-2 above 100 %
+sn2_without_sn123 = SN2 without SN123
+sum_far_from_100 = sum far from 100
+
+## Ranking
+fatty_acids = fatty acids
+panes = panes
+panes_description = include every open calculation pane
+presets = presets
+ranking = ranking
+sample = sample
+score = score
+
 key = key
+table = table
 value = value
 
 -fatty_acid_term = fatty acid
@@ -11,6 +11,8 @@ names = названия
 names_description = показать устоявшиеся названия для жирных кислот
 properties = свойства
 properties_description = показать { properties }
+reference = референс
+relative = относительное sn-2
 resize = изменить размер
 selectivity_factor = фактор селективности
 species = вид
@@ -26,6 +28,11 @@ diacylglycerol = диацилглицерин
 monoacylglycerol = моноацилглицерин
     .abbreviation = МАГ
 
+mass_composition = массовая композиция
+    .abbreviation = МК
+positional_mass_composition = позиционная массовая композиция
+    .abbreviation = ПМК
+
 configuration = конфигурация
 calculation = вычисление
 composition = композиция
@@ -55,6 +62,8 @@ from_mag = из 2-{ monoacylglycerol.abbreviation }
 
 ## Composition
 adduct = аддукт
+average_mass = средняя { mass }
+monoisotopic_mass = моноизотопная { mass }
 method = метод
 gunstone = Ганстоун
     .description = вычисление по теории Ганстоуна
@@ -72,7 +81,121 @@ ascending = по возрастанию
 descending = по убыванию
     .description = прямой порядок (от минимума к максимуму)
 
+## Comparison
+baseline = базовый образец
+comparison = сравнение
+comparison_description = выберите в настройках два или более образца, первый из них базовый
+unmatched = несопоставленные жирные кислоты
+
+## Contribution
+contribution = вклад в оценку
+
+## Reference
+no_reference = нет референса
+out_of_range = вне референсного диапазона
+reference_range = референсный диапазон
+
+## Radar
+radar = лепестковая диаграмма
+    .description = основные жирные кислоты, нормированные на референсную медиану, выберите образцы в настройках
+threshold = порог
+    .description = минимальная референсная медиана (SN123) основной жирной кислоты
+
+## Goal seek
+goal_seek = подбор параметров
+goal = цель
+zero_deviation = нулевое отклонение
+    .description = все значения в пределах референсного диапазона
+gain = прирост
+    .description = прирост оценки при исправлении только этого значения (E)
+cumulative_score = оценка после исправлений выше
+target = цель
+    .description = значение, необходимое для достижения цели, ближайшая граница референсного диапазона или частичное исправление
+
+## Sensitivity
+sensitivity = чувствительность
+perturbation = возмущение
+    .description = каждое входное значение по очереди изменяется на ±x %, записывается изменение оценки (100 - ∑F)
+
+## Explain
+explain = объяснение
+    .description = пошаговый расчет A-F для строки или оценок для итогов
+copy = копировать
+not_scored = нет в референсе, не оценивается
+
+## Import
+import = импорт
+    .description = импорт файла CSV, TSV или электронной таблицы (XLSX, XLS, ODS), перетащите его в окно или вставьте содержимое
+decimal_comma = десятичная запятая
+    .description = в числах используется запятая в качестве десятичного разделителя
+delimiter = разделитель
+header = заголовок
+    .description = первая строка содержит названия столбцов
+invalid_rows = некорректные строки пропускаются
+name = название
+range = диапазон
+    .description = диапазон ячеек каждого выбранного листа, например A1:C40, используемый диапазон если пусто
+sheets = листы
+    .description = каждый отмеченный лист становится отдельной панелью, щелкните название для предпросмотра
+source = источник
+
+## Export
+export = экспорт
+    .description = экспорт рассчитанной таблицы: индекс, жирная кислота, экспериментальные значения, A-E обеих позиций, F и итоги; Parquet сохраняет вложенные столбцы и метаданные, таблицы AsciiDoc и Markdown сохраняют сгруппированный заголовок
+export_ranking = экспорт ранжирования
+    .description = экспорт таблицы ранжирования в AsciiDoc или Markdown со сгруппированным заголовком, числа с заданной точностью
+
+## Paste
+clipboard = буфер обмена
+line = строка
+pasted_rows = вставлено строк
+
+## Selection
+selection = выделение
+    .description = щелкните ячейку, щелчок с Shift или перетаскивание выделяет диапазон, Ctrl+C копирует его с заголовком как текст с табуляцией
+
+## Workbook
+workbook = книга
+    .description = экспортировать каждую панель расчета на отдельный лист Excel (xlsx) с метаданными, исходными данными, A-F и итогами и ранжировать их на сводном листе
+no_calculation_panes = нет панелей расчета
+rank = место
+version = версия
+
+## Save
+save = сохранить
+    .description = IPC компактен, RON и JSON удобочитаемы и сравнимы, все сохраняют метаданные и открываются при перетаскивании в окно
+
+## Report
+report = отчет
+    .description = автономный HTML отчет: метаданные, исходные данные, A-F, итоги, диаграммы и референс
+charts = диаграммы
+description = описание
+input = исходные данные
+metadata = метаданные
+totals = итоги
+
+## Validation
+problems = проблемы
+    .description = проверки исходных данных при загрузке и редактировании: отрицательные и нечисловые значения, повторяющиеся жирные кислоты, суммы SN123 и SN2, далекие от 100, относительная sn-2 выше 100 %, SN2 без SN123 и индексы двойных связей за пределами длины цепи; щелкните строку, чтобы выделить ее ячейку в таблице
+duplicate_of_row = повтор строки
+index_beyond_chain = индекс двойной связи за пределами длины цепи
+negative_value = отрицательное значение
+not_a_number = не число
+relative_above_100 = относительная sn-2 выше 100 %
+sn2_without_sn123 = SN2 без SN123
+sum_far_from_100 = сумма далека от 100
+
+## Ranking
+fatty_acids = жирные кислоты
+panes = панели
+panes_description = включить все открытые панели вычислений
+presets = пресеты
+ranking = рейтинг
+sample = образец
+score = оценка
+
 key = ключ
+table = таблица
 value = значение
 
 -fatty_acid_term = { $genus ->
@@ -0,0 +1,329 @@
+use super::*;
+use crate::presets::PRESETS;
+
+const EPSILON: f64 = 1e-9;
+
+/// Vectorized calculation is equivalent to the original row-wise one for every
+/// preset
+///
+/// The original compared the absolute sn-2 of the source with the relative
+/// reference, so sn-2 is compared in the absolute mode only, see [`relative`].
+#[test]
+fn equivalence() -> PolarsResult<()> {
+    for relative in [false, true] {
+        for round in [0, 2] {
+            let settings = Settings {
+                relative,
+                round,
+                ..Settings::new()
+            };
+            for preset in PRESETS {
+                let title = preset.meta.title();
+                let expected = legacy::calculate(&preset.data, &settings)?;
+                let actual = calculate(&preset.data, &settings)?;
+                assert_eq!(
+                    actual.get_column_names(),
+                    expected.get_column_names(),
+                    "{title}"
+                );
+                assert_eq!(actual["Index"], expected["Index"], "{title}");
+                let names = if relative {
+                    &["StereospecificNumber123"][..]
+                } else {
+                    &["StereospecificNumber123", "StereospecificNumber2"][..]
+                };
+                for name in names {
+                    for (group, fields) in [
+                        ("Data", &["A", "B", "C", "D", "E", "F"][..]),
+                        ("Meta", &["Min", "Max", "Sum"][..]),
+                    ] {
+                        for field in fields {
+                            let context = format!("{title} {name}.{group}.{field} {settings:?}");
+                            assert_close(
+                                &values(&actual, name, group, field)?,
+                                &values(&expected, name, group, field)?,
+                                &context,
+                            );
+                        }
+                    }
+                }
+                if !relative {
+                    assert_close(
+                        &actual["F"].f64()?.to_vec(),
+                        &expected["F"].f64()?.to_vec(),
+                        &format!("{title} F {settings:?}"),
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Relative sn-2 of the source is 100 * SN2 / SN123 / 3
+#[test]
+fn relative() -> PolarsResult<()> {
+    let data_frame = MATURE_MILK_FAT
+        .data
+        .select(["FattyAcid"])?
+        .head(Some(2))
+        .hstack(&[
+            Column::new("StereospecificNumber123".into(), [20.0, 40.0]),
+            Column::new("StereospecificNumber2".into(), [30.0, 12.0]),
+        ])?;
+    for (relative, expected) in [(false, [30.0, 12.0]), (true, [50.0, 10.0])] {
+        let settings = Settings {
+            relative,
+            ..Settings::new()
+        };
+        let data_frame = calculate(&data_frame, &settings)?;
+        assert_eq!(
+            values(&data_frame, "StereospecificNumber123", "Data", "B")?,
+            [Some(20.0), Some(40.0)],
+        );
+        assert_close(
+            &values(&data_frame, "StereospecificNumber2", "Data", "B")?,
+            &expected.map(Some),
+            &format!("{settings:?}"),
+        );
+    }
+    Ok(())
+}
+
+fn values(
+    data_frame: &DataFrame,
+    name: &str,
+    group: &str,
+    field: &str,
+) -> PolarsResult<Vec<Option<f64>>> {
+    Ok(data_frame[name]
+        .struct_()?
+        .field_by_name(group)?
+        .struct_()?
+        .field_by_name(field)?
+        .f64()?
+        .to_vec())
+}
+
+fn assert_close(actual: &[Option<f64>], expected: &[Option<f64>], context: &str) {
+    assert_eq!(actual.len(), expected.len(), "{context}");
+    for (index, (actual, expected)) in actual.iter().zip(expected).enumerate() {
+        match (actual, expected) {
+            (Some(actual), Some(expected)) => assert!(
+                (actual - expected).abs() <= EPSILON * expected.abs().max(1.0),
+                "{context}[{index}]: {actual} != {expected}",
+            ),
+            (None, None) => {}
+            _ => panic!("{context}[{index}]: {actual:?} != {expected:?}"),
+        }
+    }
+}
+
+/// Row-wise implementation the vectorized one replaced, as of the baseline
+/// without its debug output
+mod legacy {
+    use super::{MATURE_MILK_FAT, MAX, Settings};
+    use polars::prelude::*;
+    use polars_ext::{ExprExt as _, column};
+    use std::iter::zip;
+
+    /// `Computer::try_compute` of the original implementation
+    pub(super) fn calculate(
+        data_frame: &DataFrame,
+        settings: &Settings,
+    ) -> PolarsResult<DataFrame> {
+        let mut lazy_frame = data_frame.clone().lazy();
+        let other = MATURE_MILK_FAT.data.clone().lazy().select([
+            col("FattyAcid").hash(),
+            col("FattyAcid"),
+            col("StereospecificNumber123").alias("Target123"),
+            col("StereospecificNumber2").alias("Target2"),
+        ]);
+        if !data_frame.is_empty() {
+            lazy_frame = lazy_frame
+                .select([
+                    col("FattyAcid").hash(),
+                    col("FattyAcid"),
+                    col("StereospecificNumber123").alias("Source123"),
+                    col("StereospecificNumber2").alias("Source2"),
+                ])
+                .join(
+                    other,
+                    &[col("Hash"), col("FattyAcid")],
+                    &[col("Hash"), col("FattyAcid")],
+                    JoinArgs::new(JoinType::Left),
+                )
+                .drop(["Hash"])
+                .with_column(col("FattyAcid"))
+                .with_column(if settings.relative {
+                    lit(100) * col("Source2") / col("Source123") / lit(3)
+                } else {
+                    col("Source2")
+                });
+            let output_type = GetOutput::from_type(DataType::Struct(vec![
+                Field::new(
+                    "Data".into(),
+                    DataType::Struct(vec![
+                        Field::new("A".into(), DataType::Float64),
+                        Field::new("B".into(), DataType::Float64),
+                        Field::new("C".into(), DataType::Float64),
+                        Field::new("D".into(), DataType::Float64),
+                        Field::new("E".into(), DataType::Float64),
+                        Field::new("F".into(), DataType::Float64),
+                    ]),
+                ),
+                Field::new(
+                    "Meta".into(),
+                    DataType::Struct(vec![
+                        Field::new("Min".into(), DataType::Float64),
+                        Field::new("Max".into(), DataType::Float64),
+                        Field::new("Sum".into(), DataType::Float64),
+                    ]),
+                ),
+            ]));
+            lazy_frame =
+                lazy_frame.with_columns([as_struct(vec![col("Source123"), col("Target123")])
+                    .apply(column(abcdef(settings)), output_type.clone())
+                    .alias("StereospecificNumber123")]);
+            lazy_frame = lazy_frame.with_columns([as_struct(vec![col("Source2"), col("Target2")])
+                .apply(column(abcdef(settings)), output_type)
+                .alias("StereospecificNumber2")]);
+            lazy_frame = lazy_frame.with_columns([(col("StereospecificNumber123")
+                .struct_()
+                .field_by_name("Data")
+                .struct_()
+                .field_by_name("E")
+                + col("StereospecificNumber2")
+                    .struct_()
+                    .field_by_name("Data")
+                    .struct_()
+                    .field_by_name("E"))
+            .alias("F")]);
+            lazy_frame = lazy_frame.select([
+                col("StereospecificNumber123"),
+                col("StereospecificNumber2"),
+                col("F"),
+            ]);
+        }
+        lazy_frame = lazy_frame.with_row_index("Index", None);
+        lazy_frame.collect()
+    }
+
+    fn abcdef(settings: &Settings) -> impl Fn(&Series) -> PolarsResult<Series> + 'static {
+        let settings = settings.clone();
+        move |series| {
+            let fields = series.struct_()?.fields_as_series();
+            let b = &fields[0];
+            let r#struct = match fields[1].name().as_str() {
+                "Target123" => &fields[1],
+                "Target2" if settings.relative => {
+                    &fields[1].struct_()?.field_by_name("Relative")?
+                }
+                "Target2" => &fields[1].struct_()?.field_by_name("Absolute")?,
+                _ => unreachable!(),
+            };
+            let d = r#struct.struct_()?.field_by_name("Median")?;
+            let reference_range = r#struct.struct_()?.field_by_name("ReferenceRange")?;
+            let min = reference_range.struct_()?.field_by_name("Min")?;
+            let max = reference_range.struct_()?.field_by_name("Max")?;
+            let sum = d.f64()?.sum();
+            let mut builder = Builder::new(series.len());
+            for (((b, d), min), max) in zip(b.f64()?, d.f64()?).zip(min.f64()?).zip(max.f64()?) {
+                let Some((b, d, min, max, sum)) = (|| Some((b?, d?, min?, max?, sum?)))() else {
+                    builder.append_null();
+                    continue;
+                };
+                // A
+                let a = if b < min {
+                    min
+                } else if b > max {
+                    max
+                } else {
+                    b
+                };
+                builder.a.append_value(a);
+                builder.b.append_value(b);
+                // C
+                let mut c = if a != 0.0 { (b - a).abs() / a } else { 0.0 };
+                if settings.round > 0 {
+                    let order = 10f64.powi(settings.round as _);
+                    c = (c * order).round() / order;
+                }
+                builder.c.append_value(c);
+                builder.d.append_value(d);
+                // E
+                let e = MAX * c * d / sum;
+                builder.e.append_value(e);
+                // F
+                builder.f -= e;
+            }
+            let data = builder.finish()?;
+            let meta = StructChunked::from_series(
+                "Meta".into(),
+                series.len(),
+                [
+                    min,
+                    max,
+                    Scalar::new(DataType::Float64, AnyValue::from(sum)).into_series("Sum".into()),
+                ]
+                .iter(),
+            )?
+            .into_series();
+            Ok(
+                StructChunked::from_series(PlSmallStr::EMPTY, series.len(), [data, meta].iter())?
+                    .into_series(),
+            )
+        }
+    }
+
+    /// ABCDEF builder
+    struct Builder {
+        capacity: usize,
+        a: PrimitiveChunkedBuilder<Float64Type>,
+        b: PrimitiveChunkedBuilder<Float64Type>,
+        c: PrimitiveChunkedBuilder<Float64Type>,
+        d: PrimitiveChunkedBuilder<Float64Type>,
+        e: PrimitiveChunkedBuilder<Float64Type>,
+        f: f64,
+    }
+
+    impl Builder {
+        fn new(capacity: usize) -> Self {
+            Self {
+                capacity,
+                a: PrimitiveChunkedBuilder::<Float64Type>::new("A".into(), capacity),
+                b: PrimitiveChunkedBuilder::<Float64Type>::new("B".into(), capacity),
+                c: PrimitiveChunkedBuilder::<Float64Type>::new("C".into(), capacity),
+                d: PrimitiveChunkedBuilder::<Float64Type>::new("D".into(), capacity),
+                e: PrimitiveChunkedBuilder::<Float64Type>::new("E".into(), capacity),
+                f: MAX,
+            }
+        }
+
+        fn append_null(&mut self) {
+            self.a.append_null();
+            self.b.append_null();
+            self.c.append_null();
+            self.d.append_null();
+            self.e.append_null();
+        }
+
+        fn finish(self) -> PolarsResult<Series> {
+            Ok(StructChunked::from_series(
+                "Data".into(),
+                self.capacity,
+                [
+                    self.a.finish().into_series(),
+                    self.b.finish().into_series(),
+                    self.c.finish().into_series(),
+                    self.d.finish().into_series(),
+                    self.e.finish().into_series(),
+                    Scalar::new(DataType::Float64, AnyValue::Float64(self.f))
+                        .into_series("F".into()),
+                ]
+                .iter(),
+            )?
+            .into_series())
+        }
+    }
+}
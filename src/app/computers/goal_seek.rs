@@ -0,0 +1,120 @@
+use egui::util::cache::{ComputerMut, FrameCache};
+use polars::prelude::*;
+use std::hash::{Hash, Hasher};
+
+const MAX: f64 = 50.0;
+
+/// Goal seek computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Goal seek computer
+///
+/// Inverse of `abcdef`: fixing a value (moving B into the reference range)
+/// brings its C and therefore its E to zero, so the score gained by fixing it
+/// alone is its E. Adjustments are prioritized by the gain and accumulated
+/// until the target score is reached, the last one may be partial.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    fn try_compute(&mut self, key: Key) -> PolarsResult<DataFrame> {
+        let target = key.target;
+        let position = |name: &str| {
+            key.data_frame.clone().lazy().select([
+                col("Index"),
+                lit(name).alias("Position"),
+                data(name, "A"),
+                data(name, "B"),
+                data(name, "D"),
+                data(name, "E"),
+                meta(name, "Min"),
+                meta(name, "Max"),
+                meta(name, "Sum"),
+            ])
+        };
+        let lazy_frame = concat(
+            [
+                position("StereospecificNumber123"),
+                position("StereospecificNumber2"),
+            ],
+            UnionArgs::default(),
+        )?;
+        // Score before and after the adjustment
+        let before = col("Score") - col("E");
+        // Partially fixed value: E' = E - (target - before), C' = E' * ∑D / (50 * D)
+        let c = (col("E") - (lit(target) - before.clone())) * col("Sum") / (lit(MAX) * col("D"));
+        let partial = col("A")
+            + when(col("B").gt(col("A")))
+                .then(lit(1.0))
+                .otherwise(lit(-1.0))
+                * col("A")
+                * c;
+        lazy_frame
+            .with_column((lit(100.0) - col("E").sum()).alias("Score"))
+            .filter(col("E").gt(lit(0.0)))
+            .sort(
+                ["E"],
+                SortMultipleOptions::default().with_order_descending(true),
+            )
+            .with_column((col("Score") + col("E").cum_sum(false)).alias("Score"))
+            .select([
+                col("Index"),
+                col("Position"),
+                col("B").alias("Value"),
+                col("Min"),
+                col("Max"),
+                col("E").alias("Gain"),
+                col("Score"),
+                when(before.gt_eq(lit(target)))
+                    .then(lit(NULL).cast(DataType::Float64))
+                    .when(col("Score").gt(lit(target)))
+                    .then(partial)
+                    .otherwise(col("A"))
+                    .alias("Target"),
+            ])
+            .collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+fn data(name: &str, field: &str) -> Expr {
+    col(name)
+        .struct_()
+        .field_by_name("Data")
+        .struct_()
+        .field_by_name(field)
+}
+
+fn meta(name: &str, field: &str) -> Expr {
+    col(name)
+        .struct_()
+        .field_by_name("Meta")
+        .struct_()
+        .field_by_name(field)
+}
+
+/// Goal seek key
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Key<'a> {
+    /// Calculated data frame
+    pub(crate) data_frame: &'a DataFrame,
+    /// Hash of the calculation inputs (source content hash and settings)
+    pub(crate) hash: u64,
+    /// Target score
+    pub(crate) target: f64,
+}
+
+impl Hash for Key<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+        self.target.to_bits().hash(state);
+    }
+}
+
+/// Goal seek value
+type Value = DataFrame;
@@ -0,0 +1,212 @@
+use crate::{
+    app::panes::calculation::settings::Composition,
+    r#const::{ELECTRON, monoisotopic_mass, relative_atomic_mass},
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::fatty_acid::{
+    FattyAcid, Unsaturation,
+    display::{COMMON, DisplayWithOptions},
+    polars::DataFrameExt as _,
+};
+use polars::prelude::*;
+use std::hash::{Hash, Hasher};
+
+/// Mass composition computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Mass composition computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    fn try_compute(&mut self, key: Key) -> PolarsResult<DataFrame> {
+        let fatty_acids = key.data_frame.fatty_acid();
+        let sn123 = key.data_frame["StereospecificNumber123"].f64()?;
+        let sn2 = key.data_frame["StereospecificNumber2"].f64()?;
+        // 1,3-random-2-random distribution
+        let mut acyls = Vec::with_capacity(fatty_acids.len());
+        for index in 0..fatty_acids.len() {
+            let (Some(fatty_acid), Some(sn123), Some(sn2)) =
+                (fatty_acids.get(index)?, sn123.get(index), sn2.get(index))
+            else {
+                continue;
+            };
+            let sn13 = ((3.0 * sn123 - sn2) / 2.0).max(0.0);
+            acyls.push(Acyl::new(&fatty_acid, sn13, sn2));
+        }
+        let sum13: f64 = acyls.iter().map(|acyl| acyl.sn13).sum();
+        let sum2: f64 = acyls.iter().map(|acyl| acyl.sn2).sum();
+        let mut compositions = Vec::new();
+        let mut species = Vec::new();
+        let mut values = Vec::new();
+        let mut formulas = Vec::new();
+        if sum13 > 0.0 && sum2 > 0.0 {
+            for (i, sn1) in acyls.iter().enumerate() {
+                for sn2 in &acyls {
+                    for sn3 in &acyls[i..] {
+                        // sn-1 and sn-3 are not distinguished
+                        let mut value = sn1.sn13 / sum13 * sn2.sn2 / sum2 * sn3.sn13 / sum13;
+                        if !std::ptr::eq(sn1, sn3) {
+                            value *= 2.0;
+                        }
+                        if value == 0.0 {
+                            continue;
+                        }
+                        let carbons = sn1.carbons + sn2.carbons + sn3.carbons;
+                        let bonds = sn1.bonds + sn2.bonds + sn3.bonds;
+                        compositions.push(match key.composition {
+                            Composition::Mass => format!("{carbons}:{bonds}"),
+                            Composition::PositionalMass => format!(
+                                "{}:{}-{}:{}",
+                                sn1.carbons + sn3.carbons,
+                                sn1.bonds + sn3.bonds,
+                                sn2.carbons,
+                                sn2.bonds,
+                            ),
+                        });
+                        species.push(format!("{}-{}-{}", sn1.label, sn2.label, sn3.label));
+                        values.push(100.0 * value);
+                        formulas.push(Formula::triacylglycerol(carbons, bonds));
+                    }
+                }
+            }
+        }
+        let monoisotopic = formulas
+            .iter()
+            .map(Formula::monoisotopic_mass)
+            .collect::<Vec<_>>();
+        let average = formulas
+            .iter()
+            .map(Formula::average_mass)
+            .collect::<Vec<_>>();
+        let ammonium = monoisotopic_mass::N + 4.0 * monoisotopic_mass::H - ELECTRON;
+        let sodium = monoisotopic_mass::NA - ELECTRON;
+        df! {
+            "Composition" => compositions,
+            "Species" => species,
+            "Value" => values,
+            "Monoisotopic" => monoisotopic,
+            "Average" => average,
+        }?
+        .lazy()
+        .sort(
+            ["Value"],
+            SortMultipleOptions::default().with_order_descending(true),
+        )
+        .group_by_stable([col("Composition")])
+        .agg([
+            col("Value").sum(),
+            col("Monoisotopic").first(),
+            col("Average").first(),
+            as_struct(vec![col("Species"), col("Value")]).alias("Species"),
+        ])
+        .with_columns([
+            (col("Monoisotopic") + lit(ammonium)).alias("Ammonium"),
+            (col("Monoisotopic") + lit(sodium)).alias("Sodium"),
+        ])
+        .sort(
+            ["Value"],
+            SortMultipleOptions::default().with_order_descending(true),
+        )
+        .select([
+            col("Composition"),
+            col("Value"),
+            col("Monoisotopic"),
+            col("Average"),
+            col("Ammonium"),
+            col("Sodium"),
+            col("Species"),
+        ])
+        .collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Acyl
+struct Acyl {
+    label: String,
+    carbons: u32,
+    bonds: u32,
+    sn13: f64,
+    sn2: f64,
+}
+
+impl Acyl {
+    fn new(fatty_acid: &FattyAcid, sn13: f64, sn2: f64) -> Self {
+        Self {
+            label: format!("{:#}", fatty_acid.display(COMMON)),
+            carbons: fatty_acid.carbons as _,
+            bonds: bonds(fatty_acid),
+            sn13,
+            sn2,
+        }
+    }
+}
+
+/// Formula
+struct Formula {
+    c: u32,
+    h: u32,
+    o: u32,
+}
+
+impl Formula {
+    /// C(3 + ∑c) H(8 + ∑(2c - 2d) - 6) O(6)
+    fn triacylglycerol(carbons: u32, bonds: u32) -> Self {
+        Self {
+            c: 3 + carbons,
+            h: 2 + 2 * carbons - 2 * bonds,
+            o: 6,
+        }
+    }
+
+    fn monoisotopic_mass(&self) -> f64 {
+        self.c as f64 * monoisotopic_mass::C
+            + self.h as f64 * monoisotopic_mass::H
+            + self.o as f64 * monoisotopic_mass::O
+    }
+
+    fn average_mass(&self) -> f64 {
+        self.c as f64 * relative_atomic_mass::C
+            + self.h as f64 * relative_atomic_mass::H
+            + self.o as f64 * relative_atomic_mass::O
+    }
+}
+
+/// Number of double bond equivalents (a triple bond counts twice)
+pub(crate) fn bonds(fatty_acid: &FattyAcid) -> u32 {
+    fatty_acid
+        .unsaturated
+        .iter()
+        .map(|unsaturated| match unsaturated.unsaturation {
+            Some(Unsaturation::Two) => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+/// Mass composition key
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Key<'a> {
+    pub(crate) data_frame: &'a DataFrame,
+    /// Content hash of the data frame, see [`hash_data_frame`]
+    ///
+    /// [`hash_data_frame`]: crate::utils::hash_data_frame
+    pub(crate) hash: u64,
+    pub(crate) composition: Composition,
+}
+
+impl Hash for Key<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+        self.composition.hash(state);
+    }
+}
+
+/// Mass composition value
+type Value = DataFrame;
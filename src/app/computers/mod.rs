@@ -1,3 +1,22 @@
-pub(crate) use self::calculation::{Computed as CalculationComputed, Key as CalculationKey};
+pub(crate) use self::{
+    calculation::{Computed as CalculationComputed, Key as CalculationKey},
+    goal_seek::{Computed as GoalSeekComputed, Key as GoalSeekKey},
+    mass_composition::{Computed as MassCompositionComputed, Key as MassCompositionKey},
+    ranking::{Computed as RankingComputed, Key as RankingKey, Row as RankingRow},
+    reference::{Computed as ReferenceComputed, Key as ReferenceKey},
+    selectivity::{Computed as SelectivityComputed, Key as SelectivityKey},
+    sensitivity::{Computed as SensitivityComputed, Key as SensitivityKey},
+    validation::{
+        Computed as ValidationComputed, Field as ProblemField, Key as ValidationKey,
+        Kind as ProblemKind, Problem, TOLERANCE,
+    },
+};
 
 mod calculation;
+mod goal_seek;
+mod mass_composition;
+mod ranking;
+mod reference;
+mod selectivity;
+mod sensitivity;
+mod validation;
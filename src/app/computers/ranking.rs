@@ -0,0 +1,128 @@
+use super::calculation::calculate;
+use crate::{
+    app::panes::{
+        Sample,
+        calculation::settings::Settings as CalculationSettings,
+        ranking::settings::Settings,
+        sample::{labels, scores},
+    },
+    presets::PRESETS,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use polars::prelude::*;
+use std::hash::{Hash, Hasher};
+
+/// Values of a fatty acid (SN123, SN2, F)
+const FATTY_ACID: usize = 3;
+
+/// Scores (G1, G2, G)
+const SCORE: usize = 3;
+
+/// Ranking computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Ranking computer
+///
+/// Collects one row per open pane and per selected preset.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        let mut rows = Vec::new();
+        if key.settings.panes {
+            for sample in key.samples {
+                rows.push(row(
+                    &sample.title,
+                    &sample.frame.data,
+                    &sample.settings,
+                    key.settings,
+                )?);
+            }
+        }
+        let settings = CalculationSettings::new();
+        for preset in PRESETS {
+            let title = preset.meta.title();
+            if key.settings.presets.contains(&title) {
+                rows.push(row(&title, &preset.data, &settings, key.settings)?);
+            }
+        }
+        Ok(rows)
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+fn row(
+    title: &str,
+    source: &DataFrame,
+    settings: &CalculationSettings,
+    ranking: &Settings,
+) -> PolarsResult<Row> {
+    let length = ranking.fatty_acids.len() * FATTY_ACID + SCORE;
+    let mut values = Vec::with_capacity(length);
+    if source.is_empty() {
+        values.resize(length, None);
+        return Ok(Row {
+            title: title.to_owned(),
+            values,
+        });
+    }
+    let target = calculate(source, settings)?;
+    let labels = labels(source)?;
+    for label in &ranking.fatty_acids {
+        match labels
+            .iter()
+            .position(|other| other.as_ref() == Some(label))
+        {
+            Some(index) => {
+                values.push(source["StereospecificNumber123"].f64()?.get(index));
+                values.push(source["StereospecificNumber2"].f64()?.get(index));
+                values.push(target["F"].f64()?.get(index));
+            }
+            None => values.extend([None; FATTY_ACID]),
+        }
+    }
+    values.extend(scores(&target)?);
+    Ok(Row {
+        title: title.to_owned(),
+        values,
+    })
+}
+
+/// Ranking key
+#[derive(Clone, Copy)]
+pub(crate) struct Key<'a> {
+    pub(crate) samples: &'a [Sample],
+    pub(crate) settings: &'a Settings,
+}
+
+impl Hash for Key<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if self.settings.panes {
+            for sample in self.samples {
+                sample.title.hash(state);
+                sample.hash.hash(state);
+                sample.settings.hash(state);
+            }
+        }
+        self.settings.panes.hash(state);
+        self.settings.presets.hash(state);
+        self.settings.fatty_acids.hash(state);
+    }
+}
+
+/// Ranking value
+type Value = Vec<Row>;
+
+/// Ranking row
+#[derive(Clone, Debug)]
+pub(crate) struct Row {
+    pub(crate) title: String,
+    /// SN123, SN2 and F of every fatty acid followed by the scores
+    pub(crate) values: Vec<Option<f64>>,
+}
@@ -0,0 +1,101 @@
+use crate::{
+    app::panes::calculation::settings::Settings, presets::_10_1021_jf903048p::MATURE_MILK_FAT,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use polars::prelude::*;
+use polars_ext::ExprExt as _;
+use std::hash::{Hash, Hasher};
+
+/// Reference computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Reference computer
+///
+/// Sample values side by side with the reference statistics they are
+/// compared with by the calculation.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    fn try_compute(&mut self, key: Key) -> PolarsResult<DataFrame> {
+        let reference = MATURE_MILK_FAT.data.clone().lazy().select([
+            col("FattyAcid").hash(),
+            col("FattyAcid"),
+            col("StereospecificNumber123").alias("Target123"),
+            col("StereospecificNumber2")
+                .struct_()
+                .field_by_name(if key.settings.relative {
+                    "Relative"
+                } else {
+                    "Absolute"
+                })
+                .alias("Target2"),
+        ]);
+        let source2 = if key.settings.relative {
+            lit(100) * col("StereospecificNumber2") / col("StereospecificNumber123") / lit(3)
+        } else {
+            col("StereospecificNumber2")
+        };
+        key.data_frame
+            .clone()
+            .lazy()
+            .select([
+                col("FattyAcid").hash(),
+                col("FattyAcid"),
+                col("StereospecificNumber123").alias("Source123"),
+                source2.alias("Source2"),
+            ])
+            .join(
+                reference,
+                &[col("Hash"), col("FattyAcid")],
+                &[col("Hash"), col("FattyAcid")],
+                JoinArgs::new(JoinType::Left),
+            )
+            .select([
+                col("FattyAcid"),
+                position(col("Source123"), col("Target123")).alias("StereospecificNumber123"),
+                position(col("Source2"), col("Target2")).alias("StereospecificNumber2"),
+            ])
+            .with_row_index("Index", None)
+            .collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Value, Median, InterquartileRange, Min, Max
+fn position(source: Expr, target: Expr) -> Expr {
+    let reference_range = target.clone().struct_().field_by_name("ReferenceRange");
+    as_struct(vec![
+        source.alias("Value"),
+        target.clone().struct_().field_by_name("Median"),
+        target.struct_().field_by_name("InterquartileRange"),
+        reference_range.clone().struct_().field_by_name("Min"),
+        reference_range.struct_().field_by_name("Max"),
+    ])
+}
+
+/// Reference key
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Key<'a> {
+    pub(crate) data_frame: &'a DataFrame,
+    /// Content hash of the data frame, see [`hash_data_frame`]
+    ///
+    /// [`hash_data_frame`]: crate::utils::hash_data_frame
+    pub(crate) hash: u64,
+    pub(crate) settings: &'a Settings,
+}
+
+impl Hash for Key<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+        self.settings.relative.hash(state);
+    }
+}
+
+/// Reference value
+type Value = DataFrame;
@@ -0,0 +1,108 @@
+use crate::presets::_10_1021_jf903048p::MATURE_MILK_FAT;
+use egui::util::cache::{ComputerMut, FrameCache};
+use polars::prelude::*;
+use polars_ext::ExprExt as _;
+use std::hash::{Hash, Hasher};
+
+/// Selectivity computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Selectivity computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    fn try_compute(&mut self, key: Key) -> PolarsResult<DataFrame> {
+        let reference = MATURE_MILK_FAT.data.clone().lazy().select([
+            col("FattyAcid").hash(),
+            col("FattyAcid"),
+            col("StereospecificNumber123")
+                .struct_()
+                .field_by_name("Median")
+                .alias("Target123"),
+            col("StereospecificNumber2")
+                .struct_()
+                .field_by_name("Absolute")
+                .struct_()
+                .field_by_name("Median")
+                .alias("Target2"),
+            col("StereospecificNumber2")
+                .struct_()
+                .field_by_name("Relative")
+                .struct_()
+                .field_by_name("Median")
+                .alias("TargetRelative"),
+        ]);
+        key.data_frame
+            .clone()
+            .lazy()
+            .select([
+                col("FattyAcid").hash(),
+                col("FattyAcid"),
+                col("StereospecificNumber123").alias("Source123"),
+                col("StereospecificNumber2").alias("Source2"),
+            ])
+            .join(
+                reference,
+                &[col("Hash"), col("FattyAcid")],
+                &[col("Hash"), col("FattyAcid")],
+                JoinArgs::new(JoinType::Left),
+            )
+            .select([
+                col("FattyAcid"),
+                col("Source123"),
+                col("Source2"),
+                as_struct(vec![
+                    factor(col("Source2"), col("Source123")).alias("Factor"),
+                    relative(col("Source2"), col("Source123")).alias("Relative"),
+                ])
+                .alias("Source"),
+                as_struct(vec![
+                    factor(col("Target2"), col("Target123")).alias("Factor"),
+                    col("TargetRelative").alias("Relative"),
+                ])
+                .alias("Target"),
+            ])
+            .with_row_index("Index", None)
+            .collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Enrichment factor: SN2 / SN123
+fn factor(sn2: Expr, sn123: Expr) -> Expr {
+    when(sn123.clone().neq(lit(0)))
+        .then(sn2 / sn123)
+        .otherwise(lit(NULL))
+}
+
+/// Relative sn-2 (López-López 2002): 100 * SN2 / (3 * SN123)
+fn relative(sn2: Expr, sn123: Expr) -> Expr {
+    when(sn123.clone().neq(lit(0)))
+        .then(lit(100) * sn2 / sn123 / lit(3))
+        .otherwise(lit(NULL))
+}
+
+/// Selectivity key
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Key<'a> {
+    pub(crate) data_frame: &'a DataFrame,
+    /// Content hash of the data frame, see [`hash_data_frame`]
+    ///
+    /// [`hash_data_frame`]: crate::utils::hash_data_frame
+    pub(crate) hash: u64,
+}
+
+impl Hash for Key<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
+/// Selectivity value
+type Value = DataFrame;
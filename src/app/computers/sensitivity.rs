@@ -0,0 +1,116 @@
+use super::calculation::calculate;
+use crate::app::panes::calculation::settings::Settings;
+use egui::util::cache::{ComputerMut, FrameCache};
+use polars::prelude::*;
+use std::hash::{Hash, Hasher};
+
+const POSITIONS: [&str; 2] = ["StereospecificNumber123", "StereospecificNumber2"];
+
+/// Sensitivity computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Sensitivity computer
+///
+/// Perturbs every input value by ±x % one at a time and records the change
+/// of the total score (100 - ∑F).
+///
+/// Rows are calculated independently of each other (the sum of D is the sum of
+/// the reference medians), so perturbing a value changes only the F of its
+/// row. A whole column is perturbed at once and the change of the score by a
+/// value is the negated change of the F of its row, four calculations in all.
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    fn try_compute(&mut self, key: Key) -> PolarsResult<DataFrame> {
+        let base = f(key.data_frame, key.settings)?;
+        let scored = base.sum().is_some();
+        let mut indices = Vec::new();
+        let mut positions = Vec::new();
+        let mut values = Vec::new();
+        let mut lower = Vec::new();
+        let mut upper = Vec::new();
+        for position in POSITIONS {
+            let column = key.data_frame[position].f64()?;
+            let deltas = |factor: f64| -> PolarsResult<Vec<Option<f64>>> {
+                let mut data_frame = key.data_frame.clone();
+                data_frame.with_column(column * factor)?;
+                let perturbed = f(&data_frame, key.settings)?;
+                Ok(base
+                    .iter()
+                    .zip(&perturbed)
+                    .map(|(base, perturbed)| {
+                        scored.then(|| base.unwrap_or_default() - perturbed.unwrap_or_default())
+                    })
+                    .collect())
+            };
+            let decreased = deltas(1.0 - key.perturbation / 100.0)?;
+            let increased = deltas(1.0 + key.perturbation / 100.0)?;
+            for (index, value) in column.iter().enumerate() {
+                let Some(value) = value else {
+                    continue;
+                };
+                lower.push(decreased[index]);
+                upper.push(increased[index]);
+                indices.push(index as u32);
+                positions.push(position);
+                values.push(value);
+            }
+        }
+        df! {
+            "Index" => indices,
+            "Position" => positions,
+            "Value" => values,
+            "Lower" => lower,
+            "Upper" => upper,
+        }?
+        .lazy()
+        .with_column(max_horizontal([col("Lower").abs(), col("Upper").abs()])?.alias("Influence"))
+        .sort(
+            ["Influence"],
+            SortMultipleOptions::default()
+                .with_order_descending(true)
+                .with_nulls_last(true),
+        )
+        .collect()
+    }
+}
+
+/// F of every row, the total score is 100 - ∑F
+fn f(data_frame: &DataFrame, settings: &Settings) -> PolarsResult<Float64Chunked> {
+    let target = calculate(data_frame, settings)?;
+    Ok(target["F"].f64()?.clone())
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Sensitivity key
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Key<'a> {
+    pub(crate) data_frame: &'a DataFrame,
+    /// Content hash of the data frame, see [`hash_data_frame`]
+    ///
+    /// [`hash_data_frame`]: crate::utils::hash_data_frame
+    pub(crate) hash: u64,
+    pub(crate) settings: &'a Settings,
+    /// Perturbation, %
+    pub(crate) perturbation: f64,
+}
+
+impl Hash for Key<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+        self.settings.hash(state);
+        self.perturbation.to_bits().hash(state);
+    }
+}
+
+/// Sensitivity value
+type Value = DataFrame;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,89 @@
+use super::*;
+use crate::presets::PRESETS;
+
+const EPSILON: f64 = 1e-9;
+
+/// Perturbing a whole column at once is equivalent to perturbing every value
+/// one at a time for every preset
+#[test]
+fn equivalence() -> PolarsResult<()> {
+    for relative in [false, true] {
+        let settings = Settings {
+            relative,
+            ..Settings::new()
+        };
+        for preset in PRESETS {
+            let title = preset.meta.title();
+            let key = Key {
+                data_frame: &preset.data,
+                hash: 0,
+                settings: &settings,
+                perturbation: 5.0,
+            };
+            let actual = Computer.try_compute(key)?;
+            let actual = actual.sort(["Position", "Index"], SortMultipleOptions::default())?;
+            let expected = sweep(key)?;
+            assert_eq!(actual["Index"], expected["Index"], "{title}");
+            assert_eq!(actual["Position"], expected["Position"], "{title}");
+            for name in ["Lower", "Upper"] {
+                let actual = actual[name].f64()?;
+                let expected = expected[name].f64()?;
+                for (index, (actual, expected)) in actual.iter().zip(expected).enumerate() {
+                    match (actual, expected) {
+                        (Some(actual), Some(expected)) => assert!(
+                            (actual - expected).abs() <= EPSILON * expected.abs().max(1.0),
+                            "{title} {name}[{index}] {relative}: {actual} != {expected}",
+                        ),
+                        (None, None) => {}
+                        _ => {
+                            panic!("{title} {name}[{index}] {relative}: {actual:?} != {expected:?}")
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Sweep of the values one at a time, sorted by the position and the index
+fn sweep(key: Key) -> PolarsResult<DataFrame> {
+    let score = score(key.data_frame, key.settings)?;
+    let mut indices = Vec::new();
+    let mut positions = Vec::new();
+    let mut lower = Vec::new();
+    let mut upper = Vec::new();
+    for position in POSITIONS {
+        let column = key.data_frame[position].f64()?.to_vec();
+        for (index, value) in column.iter().enumerate() {
+            let Some(value) = *value else {
+                continue;
+            };
+            let delta = |factor: f64| -> PolarsResult<Option<f64>> {
+                let mut perturbed = column.clone();
+                perturbed[index] = Some(value * factor);
+                let mut data_frame = key.data_frame.clone();
+                data_frame.with_column(Series::new(position.into(), perturbed))?;
+                Ok(score(&data_frame, key.settings)?
+                    .zip(score)
+                    .map(|(perturbed, score)| perturbed - score))
+            };
+            lower.push(delta(1.0 - key.perturbation / 100.0)?);
+            upper.push(delta(1.0 + key.perturbation / 100.0)?);
+            indices.push(index as u32);
+            positions.push(position);
+        }
+    }
+    df! {
+        "Index" => indices,
+        "Position" => positions,
+        "Lower" => lower,
+        "Upper" => upper,
+    }?
+    .sort(["Position", "Index"], SortMultipleOptions::default())
+}
+
+/// Total score (100 - ∑F)
+fn score(data_frame: &DataFrame, settings: &Settings) -> PolarsResult<Option<f64>> {
+    Ok(f(data_frame, settings)?.sum().map(|f| 100.0 - f))
+}
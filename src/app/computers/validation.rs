@@ -0,0 +1,191 @@
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::fatty_acid::{FattyAcid, polars::DataFrameExt as _};
+use polars::prelude::*;
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    hash::{Hash, Hasher},
+};
+
+/// Largest distance of the sum of a column from 100, %
+pub(crate) const TOLERANCE: f64 = 5.0;
+
+/// Validation computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Validation computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Vec<Problem>> {
+        validate(key.data_frame)
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Problems of the data frame, row by row, then the totals
+pub(crate) fn validate(data_frame: &DataFrame) -> PolarsResult<Vec<Problem>> {
+    let mut problems = Vec::new();
+    if data_frame.is_empty() {
+        return Ok(problems);
+    }
+    let fatty_acids = data_frame.fatty_acid();
+    let stereospecific_numbers123 = data_frame["StereospecificNumber123"].f64()?;
+    let stereospecific_numbers2 = data_frame["StereospecificNumber2"].f64()?;
+    let mut rows = HashMap::new();
+    for row in 0..data_frame.height() {
+        if let Some(fatty_acid) = fatty_acids.get(row)? {
+            for unsaturated in &fatty_acid.unsaturated {
+                if let Some(index) = unsaturated.index {
+                    if index == 0 || index >= fatty_acid.carbons {
+                        problems.push(Problem::new(row, Field::FattyAcid, Kind::Index(index)));
+                    }
+                }
+            }
+            match rows.entry(identity(&fatty_acid)) {
+                Entry::Occupied(entry) => {
+                    problems.push(Problem::new(
+                        row,
+                        Field::FattyAcid,
+                        Kind::Duplicate(*entry.get()),
+                    ));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(row);
+                }
+            }
+        }
+        let stereospecific_number123 = stereospecific_numbers123.get(row);
+        let stereospecific_number2 = stereospecific_numbers2.get(row);
+        for (field, value) in [
+            (Field::StereospecificNumber123, stereospecific_number123),
+            (Field::StereospecificNumber2, stereospecific_number2),
+        ] {
+            match value {
+                Some(value) if value.is_nan() => {
+                    problems.push(Problem::new(row, field, Kind::NotANumber));
+                }
+                Some(value) if value < 0.0 => {
+                    problems.push(Problem::new(row, field, Kind::Negative(value)));
+                }
+                _ => {}
+            }
+        }
+        // Relative sn-2 above 100 %: SN2 > 3 * SN123, undefined with zero SN123
+        if let (Some(sn123), Some(sn2)) = (stereospecific_number123, stereospecific_number2) {
+            if sn123 == 0.0 && sn2 > 0.0 {
+                problems.push(Problem::new(
+                    row,
+                    Field::StereospecificNumber2,
+                    Kind::WithoutTotal,
+                ));
+            } else if sn123 > 0.0 && sn2 > 3.0 * sn123 {
+                problems.push(Problem::new(
+                    row,
+                    Field::StereospecificNumber2,
+                    Kind::Relative(100.0 * sn2 / (3.0 * sn123)),
+                ));
+            }
+        }
+    }
+    for (field, values) in [
+        (Field::StereospecificNumber123, stereospecific_numbers123),
+        (Field::StereospecificNumber2, stereospecific_numbers2),
+    ] {
+        // Not a number is reported by its cell
+        let sum = values.sum().unwrap_or_default();
+        if !sum.is_nan() && (sum - 100.0).abs() > TOLERANCE {
+            problems.push(Problem::new(data_frame.height(), field, Kind::Sum(sum)));
+        }
+    }
+    Ok(problems)
+}
+
+/// Carbons and the index, isomerism and unsaturation of each bond
+fn identity(fatty_acid: &FattyAcid) -> (u8, Vec<(Option<u8>, Option<i8>, Option<u8>)>) {
+    (
+        fatty_acid.carbons,
+        fatty_acid
+            .unsaturated
+            .iter()
+            .map(|unsaturated| {
+                (
+                    unsaturated.index,
+                    unsaturated.isomerism.map(|isomerism| isomerism as _),
+                    unsaturated
+                        .unsaturation
+                        .map(|unsaturation| unsaturation as _),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Validation key
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Key<'a> {
+    pub(crate) data_frame: &'a DataFrame,
+    /// Content hash of the data frame, see [`hash_data_frame`]
+    ///
+    /// [`hash_data_frame`]: crate::utils::hash_data_frame
+    pub(crate) hash: u64,
+}
+
+impl Hash for Key<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
+/// Validation value
+type Value = Vec<Problem>;
+
+/// Problem of a cell
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Problem {
+    /// Row, the source height for the totals
+    pub(crate) row: usize,
+    pub(crate) field: Field,
+    pub(crate) kind: Kind,
+}
+
+impl Problem {
+    const fn new(row: usize, field: Field, kind: Kind) -> Self {
+        Self { row, field, kind }
+    }
+}
+
+/// Checked column
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Field {
+    FattyAcid,
+    StereospecificNumber123,
+    StereospecificNumber2,
+}
+
+/// Kind of a problem
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Kind {
+    /// Negative value
+    Negative(f64),
+    /// Not a number
+    NotANumber,
+    /// Duplicate of the fatty acid in the row
+    Duplicate(usize),
+    /// Sum of the column far from 100
+    Sum(f64),
+    /// Relative sn-2 above 100 %, %
+    Relative(f64),
+    /// SN2 above zero with zero SN123
+    WithoutTotal,
+    /// Double bond index beyond the chain length
+    Index(u8),
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,88 @@
+use super::*;
+use crate::utils::{parse_fatty_acid, sample_data_frame};
+use anyhow::Result;
+use lipid::fatty_acid::{Isomerism, Unsaturated, Unsaturation};
+
+fn data_frame(rows: &[(&str, f64, f64)]) -> Result<DataFrame> {
+    let mut fatty_acids = Vec::with_capacity(rows.len());
+    for (label, _, _) in rows {
+        fatty_acids.push(Some(parse_fatty_acid(label)?));
+    }
+    Ok(sample_data_frame(
+        &fatty_acids,
+        rows.iter().map(|row| row.1).collect(),
+        rows.iter().map(|row| row.2).collect(),
+    )?)
+}
+
+#[test]
+fn valid() -> Result<()> {
+    let data_frame = data_frame(&[
+        ("16:0", 25.0, 40.0),
+        ("18:1Δ9c", 40.0, 30.0),
+        ("18:2Δ9c,12c", 35.0, 30.0),
+    ])?;
+    assert_eq!(validate(&data_frame)?, []);
+    assert_eq!(validate(&data_frame.clear())?, []);
+    Ok(())
+}
+
+#[test]
+fn invalid() -> Result<()> {
+    let mut fatty_acids = ["16:0", "18:1Δ9c", "16:0"]
+        .into_iter()
+        .map(|label| Ok(Some(parse_fatty_acid(label)?)))
+        .collect::<Result<Vec<_>>>()?;
+    // 18:2Δ9c,20c
+    fatty_acids.push(Some(FattyAcid {
+        carbons: 18,
+        unsaturated: [9, 20]
+            .into_iter()
+            .map(|index| Unsaturated {
+                index: Some(index),
+                isomerism: Some(Isomerism::Cis),
+                unsaturation: Some(Unsaturation::One),
+            })
+            .collect(),
+    }));
+    let data_frame = sample_data_frame(
+        &fatty_acids,
+        vec![-5.0, f64::NAN, 30.0, 40.0],
+        vec![10.0, 40.0, 100.0, 30.0],
+    )?;
+    assert_eq!(
+        validate(&data_frame)?,
+        [
+            Problem::new(0, Field::StereospecificNumber123, Kind::Negative(-5.0)),
+            Problem::new(1, Field::StereospecificNumber123, Kind::NotANumber),
+            Problem::new(2, Field::FattyAcid, Kind::Duplicate(0)),
+            Problem::new(
+                2,
+                Field::StereospecificNumber2,
+                Kind::Relative(100.0 * 100.0 / 90.0),
+            ),
+            Problem::new(3, Field::FattyAcid, Kind::Index(20)),
+            Problem::new(4, Field::StereospecificNumber2, Kind::Sum(180.0)),
+        ],
+    );
+    Ok(())
+}
+
+/// Relative sn-2 is undefined with zero SN123
+#[test]
+fn without_total() -> Result<()> {
+    let data_frame = data_frame(&[
+        ("16:0", 0.0, 10.0),
+        ("18:1Δ9c", 0.0, 0.0),
+        ("18:2Δ9c,12c", 100.0, 90.0),
+    ])?;
+    assert_eq!(
+        validate(&data_frame)?,
+        [Problem::new(
+            0,
+            Field::StereospecificNumber2,
+            Kind::WithoutTotal,
+        )],
+    );
+    Ok(())
+}
@@ -2,14 +2,10 @@ use crate::{
     app::{ICON_SIZE, panes::Pane},
     presets::*,
 };
-use anyhow::Result;
 use egui::{Response, RichText, ScrollArea, Separator, Ui, Widget};
 use egui_phosphor::regular::DATABASE;
 use egui_tiles::Tree;
 use egui_tiles_ext::{TreeExt, VERTICAL};
-use metadata::MetaDataFrame;
-use ron::{extensions::Extensions, ser::PrettyConfig};
-use std::fs::File;
 
 /// Load
 pub(crate) struct Load<'a> {
@@ -28,7 +24,8 @@ impl Load<'_> {
                 .button(RichText::new(format!("{DATABASE} {}", $frame.meta.title())).heading())
                 .clicked()
             {
-                self.tree.insert_pane::<VERTICAL>(Pane::new($frame.clone()));
+                self.tree
+                    .insert_pane::<VERTICAL>(Pane::calculation($frame.clone()));
             }
         }
 
@@ -98,29 +95,3 @@ fn doi_separator(doi: &str) -> impl Fn(&mut Ui) -> Response {
         .response
     }
 }
-
-fn ipc(name: &str, frame: &mut MetaDataFrame) -> Result<()> {
-    let file = File::create(name)?;
-    MetaDataFrame::new(frame.meta.clone(), &mut frame.data).write(file)?;
-    Ok(())
-}
-
-fn ron(name: &str, frame: &mut MetaDataFrame) -> Result<()> {
-    let file = File::create(name)?;
-    ron::ser::to_writer_pretty(
-        file,
-        &frame.data,
-        PrettyConfig::default().extensions(Extensions::IMPLICIT_SOME),
-    )?;
-    Ok(())
-}
-// fn json(name: &str, frame: &mut MetaDataFrame) -> Result<()> {
-//     // let contents = ron::ser::to_string_pretty(
-//     //     &frame.data,
-//     //     PrettyConfig::new().extensions(Extensions::IMPLICIT_SOME | Extensions::UNWRAP_NEWTYPES),
-//     // )?;
-//     let contents = serde_json::to_string(&frame.data)?;
-//     println!("contents: {contents}");
-//     std::fs::write(name, contents)?;
-//     Ok(())
-// }
@@ -1,26 +1,43 @@
 use self::{
     menu::load::Load,
-    panes::{Pane, behavior::Behavior},
-    windows::About,
+    panes::{
+        Pane,
+        behavior::Behavior,
+        calculation::{self, flatten},
+        sample::scores,
+    },
+    windows::{About, Import},
+};
+use crate::{
+    localization::{UiExt, localize},
+    utils::{
+        doi, export, load, parse_fatty_acid, parse_number, resolve_fatty_acid, sample_data_frame,
+        worksheet_name, write_worksheet,
+    },
 };
-use crate::localization::{UiExt, localize};
+use anyhow::{Context as _, Result, bail, ensure};
 use eframe::{APP_KEY, CreationContext, Storage, get_value, set_value};
 use egui::{
-    Align, Align2, CentralPanel, Color32, Context, FontDefinitions, Frame, Id, LayerId, Layout,
-    Order, RichText, ScrollArea, Sides, TextStyle, TopBottomPanel, Vec2, Visuals, menu::bar, vec2,
-    warn_if_debug_build,
+    Align, Align2, CentralPanel, Color32, Context, DroppedFile, Event, FontDefinitions, Frame, Id,
+    LayerId, Layout, Order, RichText, ScrollArea, Sides, TextStyle, TopBottomPanel, Ui, Vec2,
+    Visuals, menu::bar, vec2, warn_if_debug_build,
 };
 use egui_ext::{DroppedFileExt, HoveredFileExt, LabeledSeparator, LightDarkButton};
 use egui_notify::Toasts;
 use egui_phosphor::{
     Variant, add_to_fonts,
     regular::{
-        ARROWS_CLOCKWISE, ARROWS_HORIZONTAL, GRID_FOUR, INFO, PENCIL, PLUS,
-        SQUARE_SPLIT_HORIZONTAL, SQUARE_SPLIT_VERTICAL, TABS, TRASH,
+        ARROWS_CLOCKWISE, ARROWS_HORIZONTAL, CHART_POLAR, FILE_CSV, FILE_XLS, GIT_DIFF, GRID_FOUR,
+        INFO, LIST_NUMBERS, PENCIL, PLUS, SQUARE_SPLIT_HORIZONTAL, SQUARE_SPLIT_VERTICAL, TABS,
+        TRASH,
     },
 };
-use egui_tiles::{ContainerKind, Tile, Tree};
-use egui_tiles_ext::TilesExt as _;
+use egui_tiles::{ContainerKind, Tile, TileId, Tree};
+use egui_tiles_ext::{TilesExt as _, TreeExt as _, VERTICAL};
+use lipid::fatty_acid::FattyAcid;
+use metadata::MetaDataFrame;
+use polars::prelude::*;
+use rust_xlsxwriter::Workbook;
 use serde::{Deserialize, Serialize};
 use std::{
     borrow::BorrowMut,
@@ -62,11 +79,17 @@ pub struct App {
 
     // Data channel
     #[serde(skip)]
-    channel: (Sender<(String, String)>, Receiver<(String, String)>),
+    channel: (Sender<(String, Vec<u8>)>, Receiver<(String, Vec<u8>)>),
+
+    // Focused pane
+    #[serde(skip)]
+    focused: Option<TileId>,
 
     // Windows
     #[serde(skip)]
     about: About,
+    #[serde(skip)]
+    import: Import,
     // Notifications
     #[serde(skip)]
     toasts: Toasts,
@@ -78,8 +101,10 @@ impl Default for App {
             left_panel: true,
             tree: Tree::empty("central_tree"),
             channel: channel(),
+            focused: None,
             toasts: Default::default(),
             about: Default::default(),
+            import: Default::default(),
         }
     }
 }
@@ -143,10 +168,27 @@ impl App {
         CentralPanel::default()
             .frame(Frame::central_panel(&ctx.style()))
             .show(ctx, |ui| {
-                let mut behavior = Behavior { close: None };
+                let samples = self
+                    .tree
+                    .tiles
+                    .tiles()
+                    .filter_map(|tile| match tile {
+                        Tile::Pane(pane) => pane.sample(),
+                        Tile::Container(_) => None,
+                    })
+                    .collect();
+                let mut behavior = Behavior {
+                    close: None,
+                    focused: self.focused,
+                    samples,
+                };
                 self.tree.ui(&mut behavior, ui);
+                self.focused = behavior.focused;
                 if let Some(id) = behavior.close {
                     self.tree.tiles.remove(id);
+                    if self.focused == Some(id) {
+                        self.focused = None;
+                    }
                 }
             });
     }
@@ -234,10 +276,10 @@ impl App {
                     {
                         let mut panes = self.tree.tiles.panes_mut().peekable();
                         if let Some(pane) = panes.peek() {
-                            resizable ^= pane.settings.resizable;
+                            resizable ^= pane.resizable();
                         }
                         for pane in panes {
-                            pane.settings.resizable = resizable;
+                            pane.set_resizable(resizable);
                         }
                     };
                     // Editable
@@ -247,7 +289,15 @@ impl App {
                         .on_hover_text(localize!("edit"))
                         .clicked()
                     {
-                        let mut panes = self.tree.tiles.panes_mut().peekable();
+                        let mut panes = self
+                            .tree
+                            .tiles
+                            .panes_mut()
+                            .filter_map(|pane| match pane {
+                                Pane::Calculation(pane) => Some(pane),
+                                _ => None,
+                            })
+                            .peekable();
                         if let Some(pane) = panes.peek() {
                             editable ^= pane.settings.editable;
                         }
@@ -258,10 +308,56 @@ impl App {
                     ui.separator();
                     // Load
                     ui.add(Load::new(&mut self.tree));
+                    // Import
+                    ui.toggle_value(
+                        &mut self.import.open,
+                        RichText::new(FILE_CSV).size(ICON_SIZE),
+                    )
+                    .on_hover_text(localize!("import"))
+                    .on_hover_text(localize!("import.description"));
+                    // Workbook
+                    if ui
+                        .button(RichText::new(FILE_XLS).size(ICON_SIZE))
+                        .on_hover_text(localize!("workbook"))
+                        .on_hover_text(localize!("workbook.description"))
+                        .clicked()
+                    {
+                        if let Err(error) = self.workbook(ui) {
+                            error!(%error);
+                            self.toasts
+                                .error(error.to_string())
+                                .closable(true)
+                                .duration(Some(NOTIFICATIONS_DURATION));
+                        }
+                    }
                     // Create
                     if ui.button(RichText::new(PLUS).size(ICON_SIZE)).clicked() {
                         // self.tree.insert_pane::<VERTICAL>(Pane::new());
                     }
+                    // Ranking
+                    if ui
+                        .button(RichText::new(LIST_NUMBERS).size(ICON_SIZE))
+                        .on_hover_text(localize!("ranking"))
+                        .clicked()
+                    {
+                        self.tree.insert_pane::<VERTICAL>(Pane::ranking());
+                    }
+                    // Comparison
+                    if ui
+                        .button(RichText::new(GIT_DIFF).size(ICON_SIZE))
+                        .on_hover_text(localize!("comparison"))
+                        .clicked()
+                    {
+                        self.tree.insert_pane::<VERTICAL>(Pane::comparison());
+                    }
+                    // Radar
+                    if ui
+                        .button(RichText::new(CHART_POLAR).size(ICON_SIZE))
+                        .on_hover_text(localize!("radar"))
+                        .clicked()
+                    {
+                        self.tree.insert_pane::<VERTICAL>(Pane::radar());
+                    }
                     ui.separator();
                     // About
                     ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
@@ -287,6 +383,23 @@ impl App {
 impl App {
     fn windows(&mut self, ctx: &Context) {
         self.about.window(ctx);
+        match self.import.window(ctx) {
+            Some(Ok(frames)) => {
+                for frame in frames {
+                    let mut pane = calculation::Pane::new(frame);
+                    pane.settings.editable = true;
+                    self.tree.insert_pane::<VERTICAL>(Pane::Calculation(pane));
+                }
+            }
+            Some(Err(error)) => {
+                error!(%error);
+                self.toasts
+                    .error(error.to_string())
+                    .closable(true)
+                    .duration(Some(NOTIFICATIONS_DURATION));
+            }
+            None => {}
+        }
     }
 }
 
@@ -329,8 +442,9 @@ impl App {
             info!(?dropped_files);
             for dropped in dropped_files {
                 trace!(?dropped);
-                let content = match dropped.content() {
-                    Ok(content) => content,
+                let name = dropped.name();
+                let bytes = match bytes(&dropped) {
+                    Ok(bytes) => bytes,
                     Err(error) => {
                         error!(%error);
                         self.toasts
@@ -340,80 +454,231 @@ impl App {
                         continue;
                     }
                 };
-                trace!(content);
-                let name = dropped.name();
-                self.channel
-                    .0
-                    .send((
-                        name.strip_suffix(".ron")
-                            .and_then(|name| name.strip_suffix(".hmf"))
-                            .unwrap_or(name)
-                            .to_owned(),
-                        content,
-                    ))
-                    .ok();
+                let result: Result<()> = if [".csv", ".tsv", ".txt"]
+                    .iter()
+                    .any(|extension| name.ends_with(extension))
+                {
+                    String::from_utf8(bytes)
+                        .map(|text| self.import.load(name, text))
+                        .map_err(From::from)
+                } else if [".xlsx", ".xlsm", ".xls", ".ods"]
+                    .iter()
+                    .any(|extension| name.ends_with(extension))
+                {
+                    self.import.load_spreadsheet(name, bytes)
+                } else {
+                    self.channel
+                        .0
+                        .send((name.to_owned(), bytes))
+                        .map_err(From::from)
+                };
+                if let Err(error) = result {
+                    error!(%error);
+                    self.toasts
+                        .error(format!("{}: {error}", dropped.display()))
+                        .closable(true)
+                        .duration(Some(NOTIFICATIONS_DURATION));
+                }
             }
         }
     }
 
-    // fn paste(&mut self, ctx: &Context) {
-    //     if !ctx.memory(|memory| memory.focused().is_some()) {
-    //         ctx.input(|input| {
-    //             for event in &input.raw.events {
-    //                 if let Event::Paste(paste) = event {
-    //                     if let Err(error) = self.parse(paste) {
-    //                         error!(?error);
-    //                         self.toasts
-    //                             .error(error.to_string().chars().take(64).collect::<String>())
-    //                             .set_duration(Some(Duration::from_secs(5)))
-    //                             .set_closable(true);
-    //                     }
-    //                 }
-    //             }
-    //         });
-    //     }
-    // }
+    /// Opens the received files (dropped IPC and RON) as new panes
+    fn receive(&mut self) {
+        while let Ok((name, bytes)) = self.channel.1.try_recv() {
+            trace!(name, bytes = bytes.len());
+            match load(&name, &bytes) {
+                Ok(frame) => {
+                    self.tree.insert_pane::<VERTICAL>(Pane::calculation(frame));
+                }
+                Err(error) => {
+                    error!(%error);
+                    self.toasts
+                        .error(format!("{name}: {error}"))
+                        .closable(true)
+                        .duration(Some(NOTIFICATIONS_DURATION));
+                }
+            }
+        }
+    }
 
-    // fn parse(&mut self, paste: &str) -> Result<()> {
-    //     use crate::parsers::whitespace::Parser;
-    //     let parsed = Parser::parse(paste)?;
-    //     debug!(?parsed);
-    //     for parsed in parsed {
-    //         // self.docks.central.tabs.input.add(match parsed {
-    //         //     Parsed::All(label, (c, n), tag, dag, mag) => FattyAcid {
-    //         //         label,
-    //         //         formula: ether!(c as usize, n as usize),
-    //         //         values: [tag, dag, mag],
-    //         //     },
-    //         //     // Parsed::String(label) => Row { label, ..default() },
-    //         //     // Parsed::Integers(_) => Row { label, ..default() },
-    //         //     // Parsed::Float(tag) => Row { label, ..default() },
-    //         //     _ => unimplemented!(),
-    //         // })?;
-    //         // self.config.push_row(Row {
-    //         //     acylglycerols,
-    //         //     label:  parsed.,
-    //         //     ether: todo!(),
-    //         //     // ..default()
-    //         // })?;
-    //     }
-    //     // let mut rows = Vec::new();
-    //     // for row in paste.split('\n') {
-    //     //     let mut columns = [0.0; COUNT];
-    //     //     for (j, column) in row.split('\t').enumerate() {
-    //     //         ensure!(j < COUNT, "Invalid shape, columns: {COUNT} {j}");
-    //     //         columns[j] = column.replace(',', ".").parse()?;
-    //     //     }
-    //     //     rows.push(columns);
-    //     // }
-    //     // for acylglycerols in rows {
-    //     //     self.config.push_row(Row {
-    //     //         acylglycerol: acylglycerols,
-    //     //         ..default()
-    //     //     })?;
-    //     // }
-    //     Ok(())
-    // }
+    fn paste(&mut self, ctx: &Context) {
+        if ctx.memory(|memory| memory.focused().is_some()) {
+            return;
+        }
+        let pastes = ctx.input(|input| {
+            input
+                .raw
+                .events
+                .iter()
+                .filter_map(|event| match event {
+                    Event::Paste(paste) => Some(paste.clone()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        });
+        for paste in pastes {
+            if let Err(error) = self.parse(&paste) {
+                error!(%error);
+                self.toasts
+                    .error(error.to_string())
+                    .closable(true)
+                    .duration(Some(NOTIFICATIONS_DURATION));
+            }
+        }
+    }
+
+    /// Appends pasted rows to the focused editable pane or to a new one
+    fn parse(&mut self, paste: &str) -> Result<()> {
+        let mut fatty_acids = Vec::new();
+        let mut stereospecific_numbers123 = Vec::new();
+        let mut stereospecific_numbers2 = Vec::new();
+        let mut lines = paste
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .peekable();
+        // Header
+        if let Some((_, line)) = lines.peek() {
+            if parse_line(line).is_err() && is_header(line) {
+                lines.next();
+            }
+        }
+        for (index, line) in lines {
+            match parse_line(line) {
+                Ok((fatty_acid, stereospecific_number123, stereospecific_number2)) => {
+                    fatty_acids.push(Some(fatty_acid));
+                    stereospecific_numbers123.push(stereospecific_number123);
+                    stereospecific_numbers2.push(stereospecific_number2);
+                }
+                Err(error) => {
+                    self.toasts
+                        .warning(format!("{} {}: {error}", localize!("line"), index + 1))
+                        .closable(true)
+                        .duration(Some(NOTIFICATIONS_DURATION));
+                }
+            }
+        }
+        if fatty_acids.is_empty() {
+            return Ok(());
+        }
+        let count = fatty_acids.len();
+        let data_frame = sample_data_frame(
+            &fatty_acids,
+            stereospecific_numbers123,
+            stereospecific_numbers2,
+        )?;
+        let focused = match self.focused.and_then(|id| self.tree.tiles.get_mut(id)) {
+            Some(Tile::Pane(Pane::Calculation(pane))) if pane.settings.editable => Some(pane),
+            _ => None,
+        };
+        let title = if let Some(pane) = focused {
+            pane.append(&data_frame)?;
+            pane.title()
+        } else {
+            let mut frame = MetaDataFrame::new(Default::default(), data_frame);
+            frame.meta.name = localize!("clipboard");
+            let mut pane = calculation::Pane::new(frame);
+            pane.settings.editable = true;
+            let title = pane.title();
+            self.tree.insert_pane::<VERTICAL>(Pane::Calculation(pane));
+            title
+        };
+        self.toasts
+            .info(format!("{title}: {} {count}", localize!("pasted_rows")))
+            .closable(true)
+            .duration(Some(NOTIFICATIONS_DURATION));
+        Ok(())
+    }
+
+    /// Exports every calculation pane to its own worksheet after a ranking
+    /// worksheet
+    fn workbook(&self, ui: &Ui) -> Result<()> {
+        let samples = self
+            .tree
+            .tiles
+            .tiles()
+            .filter_map(|tile| match tile {
+                Tile::Pane(pane) => pane.sample(),
+                Tile::Container(_) => None,
+            })
+            .collect::<Vec<_>>();
+        ensure!(!samples.is_empty(), localize!("no_calculation_panes"));
+        let mut workbook = Workbook::new();
+        let mut names = Vec::with_capacity(samples.len() + 1);
+        // Ranking
+        let mut ranking = Vec::with_capacity(samples.len());
+        let mut targets = Vec::with_capacity(samples.len());
+        for sample in &samples {
+            let target = sample.target(ui);
+            ranking.push((sample, scores(&target)?));
+            targets.push(target);
+        }
+        ranking.sort_by(|(_, left), (_, right)| {
+            right[2]
+                .unwrap_or(f64::NEG_INFINITY)
+                .total_cmp(&left[2].unwrap_or(f64::NEG_INFINITY))
+        });
+        let data_frame = DataFrame::new(vec![
+            Column::new(
+                localize!("rank").into(),
+                (1..=ranking.len() as u32).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                localize!("sample").into(),
+                ranking
+                    .iter()
+                    .map(|(sample, _)| sample.title.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "G1".into(),
+                ranking
+                    .iter()
+                    .map(|(_, scores)| scores[0])
+                    .collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "G2".into(),
+                ranking
+                    .iter()
+                    .map(|(_, scores)| scores[1])
+                    .collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "G".into(),
+                ranking
+                    .iter()
+                    .map(|(_, scores)| scores[2])
+                    .collect::<Vec<_>>(),
+            ),
+        ])?;
+        let precision = samples[0].settings.precision;
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(worksheet_name(&localize!("ranking"), &mut names))?;
+        write_worksheet(worksheet, &[], &data_frame, precision)?;
+        // Panes
+        for (sample, target) in samples.iter().zip(&targets) {
+            let data_frame = flatten(&sample.frame.data, target)?;
+            let meta = &sample.frame.meta;
+            let mut metadata = vec![(localize!("name"), meta.name.clone())];
+            if let Some(version) = &meta.version {
+                metadata.push((localize!("version"), version.to_string()));
+            }
+            if let Some(doi) = doi(&meta.description) {
+                metadata.push(("DOI".to_owned(), doi.to_owned()));
+            }
+            let metadata = metadata
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.clone()))
+                .collect::<Vec<_>>();
+            let worksheet = workbook.add_worksheet();
+            worksheet.set_name(worksheet_name(&sample.title, &mut names))?;
+            write_worksheet(worksheet, &metadata, &data_frame, sample.settings.precision)?;
+        }
+        export("HMF.xlsx", &workbook.save_to_buffer()?)?;
+        Ok(())
+    }
 
     // fn export(&self) -> Result<(), impl Debug> {
     //     let content = to_string(&TomlParsed {
@@ -435,6 +700,68 @@ impl App {
     // }
 }
 
+/// Content of a dropped file: its bytes on the web, the file natively
+fn bytes(dropped: &DroppedFile) -> Result<Vec<u8>> {
+    if let Some(bytes) = &dropped.bytes {
+        return Ok(bytes.to_vec());
+    }
+    let path = dropped.path.as_ref().context("no content")?;
+    Ok(std::fs::read(path)?)
+}
+
+/// Parses a pasted line: a fatty acid label followed by SN123 and SN2
+///
+/// Fields are separated by tabs (spreadsheets) or whitespace (PDF tables),
+/// numbers may use a decimal comma.
+fn parse_line(line: &str) -> Result<(FattyAcid, f64, f64)> {
+    let fields = fields(line);
+    let [label @ .., stereospecific_number123, stereospecific_number2] = &fields[..] else {
+        bail!(
+            "expected a fatty acid, SN123 and SN2, found `{}`",
+            line.trim()
+        );
+    };
+    ensure!(
+        !label.is_empty(),
+        "expected a fatty acid, found `{}`",
+        line.trim()
+    );
+    let number = |text: &str| -> Result<f64> {
+        let value = parse_number(text, true)?.context("expected a number")?;
+        ensure!(
+            value.is_finite() && value >= 0.0,
+            "expected a non-negative number, found `{text}`"
+        );
+        Ok(value)
+    };
+    Ok((
+        resolve_fatty_acid(parse_fatty_acid(&label.join(" "))?),
+        number(stereospecific_number123)?,
+        number(stereospecific_number2)?,
+    ))
+}
+
+/// Checks that the line is a header: none of the SN123 and SN2 fields is a
+/// number (`FA SN123 SN2`, `Fatty acid, sn-1,2,3, sn-2`)
+fn is_header(line: &str) -> bool {
+    let fields = fields(line);
+    fields[fields.len().saturating_sub(2)..]
+        .iter()
+        .all(|field| !matches!(parse_number(field, true), Ok(Some(_))))
+}
+
+/// Fields of a pasted line separated by tabs or whitespace
+fn fields(line: &str) -> Vec<&str> {
+    if line.contains('\t') {
+        line.split('\t')
+            .map(str::trim)
+            .filter(|field| !field.is_empty())
+            .collect()
+    } else {
+        line.split_whitespace().collect()
+    }
+}
+
 impl eframe::App for App {
     /// Called by the frame work to save state before shutdown.
     fn save(&mut self, storage: &mut dyn Storage) {
@@ -451,11 +778,14 @@ impl eframe::App for App {
         self.notifications(ctx);
         // Post update
         self.drag_and_drop(ctx);
+        self.receive();
+        self.paste(ctx);
     }
 }
 
 mod computers;
 mod menu;
 mod panes;
+mod report;
 mod widgets;
 mod windows;
@@ -1,12 +1,14 @@
-use super::Pane;
+use super::{Pane, Sample};
 use egui::{RichText, Sides, Ui, WidgetText};
 use egui_phosphor::regular::X;
 use egui_tiles::{TileId, UiResponse};
 
 /// Behavior
-#[derive(Debug)]
 pub(crate) struct Behavior {
     pub(crate) close: Option<TileId>,
+    /// Last pane clicked into
+    pub(crate) focused: Option<TileId>,
+    pub(crate) samples: Vec<Sample>,
 }
 
 impl egui_tiles::Behavior<Pane> for Behavior {
@@ -82,7 +84,10 @@ impl egui_tiles::Behavior<Pane> for Behavior {
                 },
             )
             .0;
-        pane.body(ui);
+        pane.body(ui, &self.samples);
+        if ui.ui_contains_pointer() && ui.input(|input| input.pointer.any_pressed()) {
+            self.focused = Some(tile_id);
+        }
         if response.dragged() {
             UiResponse::DragStarted
         } else {
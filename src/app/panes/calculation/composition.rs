@@ -0,0 +1,115 @@
+use super::{ID_SOURCE, Settings};
+use crate::{
+    app::computers::{MassCompositionComputed, MassCompositionKey},
+    localization::localize,
+};
+use egui::{Grid, Id, ScrollArea, Ui};
+use polars::prelude::*;
+
+/// m/z is reported with four decimal places
+const MASS_PRECISION: usize = 4;
+
+/// Mass composition view
+pub(super) struct CompositionView<'a> {
+    data_frame: &'a DataFrame,
+    hash: u64,
+    settings: &'a Settings,
+}
+
+impl<'a> CompositionView<'a> {
+    pub(super) fn new(data_frame: &'a DataFrame, hash: u64, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            hash,
+            settings,
+        }
+    }
+}
+
+impl CompositionView<'_> {
+    pub(super) fn show(&self, ui: &mut Ui) {
+        if let Err(error) = self.try_show(ui) {
+            ui.label(error.to_string());
+        }
+    }
+
+    fn try_show(&self, ui: &mut Ui) -> PolarsResult<()> {
+        if self.data_frame.is_empty() {
+            return Ok(());
+        }
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<MassCompositionComputed>()
+                .get(MassCompositionKey {
+                    data_frame: self.data_frame,
+                    hash: self.hash,
+                    composition: self.settings.composition,
+                })
+        });
+        let precision = self.settings.precision;
+        let compositions = data_frame["Composition"].str()?;
+        let values = data_frame["Value"].f64()?;
+        let monoisotopic = data_frame["Monoisotopic"].f64()?;
+        let average = data_frame["Average"].f64()?;
+        let ammonium = data_frame["Ammonium"].f64()?;
+        let sodium = data_frame["Sodium"].f64()?;
+        let species = data_frame["Species"].list()?;
+        ScrollArea::both()
+            .show(ui, |ui| -> PolarsResult<()> {
+                let id_salt = Id::new(ID_SOURCE).with("Composition");
+                Grid::new(id_salt)
+                    .striped(true)
+                    .show(ui, |ui| -> PolarsResult<()> {
+                        ui.heading(localize!("composition"));
+                        ui.heading(localize!("value"));
+                        ui.heading("M")
+                            .on_hover_text(localize!("monoisotopic_mass"));
+                        ui.heading("⟨M⟩").on_hover_text(localize!("average_mass"));
+                        ui.heading("[M+NH4]+").on_hover_text(localize!("adduct"));
+                        ui.heading("[M+Na]+").on_hover_text(localize!("adduct"));
+                        ui.end_row();
+                        for row in 0..data_frame.height() {
+                            let composition = compositions.get(row).unwrap_or_default();
+                            let response = ui.label(composition);
+                            if let Some(species) = species.get_as_series(row) {
+                                let labels = species.struct_()?.field_by_name("Species")?;
+                                let values = species.struct_()?.field_by_name("Value")?;
+                                let (labels, values) = (labels.str()?, values.f64()?);
+                                response.on_hover_ui(|ui| {
+                                    ui.heading(localize!("species"));
+                                    Grid::new(ui.next_auto_id()).show(ui, |ui| {
+                                        for (label, value) in labels.iter().zip(values.iter()) {
+                                            ui.label(label.unwrap_or_default());
+                                            if let Some(value) = value {
+                                                ui.label(format!("{value:.precision$}"));
+                                            }
+                                            ui.end_row();
+                                        }
+                                    });
+                                });
+                            }
+                            match values.get(row) {
+                                Some(value) => ui.label(format!("{value:.precision$}")),
+                                None => ui.label(AnyValue::Null.to_string()),
+                            };
+                            for value in [
+                                monoisotopic.get(row),
+                                average.get(row),
+                                ammonium.get(row),
+                                sodium.get(row),
+                            ] {
+                                match value {
+                                    Some(value) => ui.label(format!("{value:.MASS_PRECISION$}")),
+                                    None => ui.label(AnyValue::Null.to_string()),
+                                };
+                            }
+                            ui.end_row();
+                        }
+                        Ok(())
+                    })
+                    .inner
+            })
+            .inner
+    }
+}
@@ -0,0 +1,161 @@
+use super::{ID_SOURCE, Settings};
+use crate::{
+    app::{
+        computers::{CalculationComputed, CalculationKey},
+        panes::sample::field,
+    },
+    localization::localize,
+};
+use egui::{Id, Ui};
+use egui_plot::{Bar, BarChart, Legend, Plot};
+use lipid::fatty_acid::{
+    display::{COMMON, DisplayWithOptions},
+    polars::DataFrameExt as _,
+};
+use polars::prelude::*;
+
+/// Score contribution view
+pub(super) struct ContributionView<'a> {
+    data_frame: &'a DataFrame,
+    hash: u64,
+    settings: &'a Settings,
+}
+
+impl<'a> ContributionView<'a> {
+    pub(super) fn new(data_frame: &'a DataFrame, hash: u64, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            hash,
+            settings,
+        }
+    }
+}
+
+impl ContributionView<'_> {
+    pub(super) fn show(&self, ui: &mut Ui) {
+        if let Err(error) = self.try_show(ui) {
+            ui.label(error.to_string());
+        }
+    }
+
+    fn try_show(&self, ui: &mut Ui) -> PolarsResult<()> {
+        if self.data_frame.is_empty() {
+            return Ok(());
+        }
+        let target = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<CalculationComputed>()
+                .get(CalculationKey {
+                    data_frame: self.data_frame,
+                    hash: self.hash,
+                    settings: self.settings,
+                })
+        });
+        let fatty_acids = self.data_frame.fatty_acid();
+        let sn123 = Position::new(&target, "StereospecificNumber123")?;
+        let sn2 = Position::new(&target, "StereospecificNumber2")?;
+        let f = target["F"].f64()?;
+        // Sort by impact
+        let mut rows = Vec::with_capacity(fatty_acids.len());
+        for index in 0..fatty_acids.len() {
+            let label = fatty_acids
+                .get(index)?
+                .map(|fatty_acid| format!("{:#}", (&fatty_acid).display(COMMON)))
+                .unwrap_or_default();
+            rows.push((index, label, f.get(index).unwrap_or_default()));
+        }
+        rows.sort_by(|left, right| right.2.total_cmp(&left.2));
+        let labels = rows
+            .iter()
+            .map(|(_, label, _)| label.clone())
+            .collect::<Vec<_>>();
+        let precision = self.settings.precision;
+        let sn123 = sn123.chart("SN123", &rows, precision);
+        let sn2 = sn2.chart("SN2", &rows, precision).stack_on(&[&sn123]);
+        Plot::new(Id::new(ID_SOURCE).with("Contribution"))
+            .legend(Legend::default())
+            .allow_drag(false)
+            .allow_scroll(false)
+            .x_axis_label(localize!("fatty_acid"))
+            .y_axis_label("E")
+            .x_axis_formatter(move |mark, _| {
+                if mark.value.fract() != 0.0 || mark.value < 0.0 {
+                    return String::new();
+                }
+                labels.get(mark.value as usize).cloned().unwrap_or_default()
+            })
+            .show(ui, |ui| {
+                ui.bar_chart(sn123);
+                ui.bar_chart(sn2);
+            });
+        Ok(())
+    }
+}
+
+/// Calculated values of a stereospecific position
+struct Position {
+    a: Vec<Option<f64>>,
+    b: Vec<Option<f64>>,
+    c: Vec<Option<f64>>,
+    d: Vec<Option<f64>>,
+    e: Vec<Option<f64>>,
+    min: Vec<Option<f64>>,
+    max: Vec<Option<f64>>,
+    sum: Vec<Option<f64>>,
+}
+
+impl Position {
+    fn new(target: &DataFrame, name: &str) -> PolarsResult<Self> {
+        Ok(Self {
+            a: field(target, name, "Data", "A")?,
+            b: field(target, name, "Data", "B")?,
+            c: field(target, name, "Data", "C")?,
+            d: field(target, name, "Data", "D")?,
+            e: field(target, name, "Data", "E")?,
+            min: field(target, name, "Meta", "Min")?,
+            max: field(target, name, "Meta", "Max")?,
+            sum: field(target, name, "Meta", "Sum")?,
+        })
+    }
+
+    /// A/B/C/D breakdown, the same as the table tooltips
+    fn breakdown(&self, index: usize, precision: usize) -> String {
+        let format = |value: Option<f64>| match value {
+            Some(value) => format!("{value:.precision$}"),
+            None => AnyValue::Null.to_string(),
+        };
+        let (min, max) = (format(self.min[index]), format(self.max[index]));
+        let (a, b, c, d, e, sum) = (
+            format(self.a[index]),
+            format(self.b[index]),
+            format(self.c[index]),
+            format(self.d[index]),
+            format(self.e[index]),
+            format(self.sum[index]),
+        );
+        format!(
+            "A = {a} ∈ [{min}, {max}]\nB = {b}\nC = |{a} - {b}| / {a} = {c}\nD = {d}\nE = 50 * {c} * {d} / {sum} = {e}"
+        )
+    }
+
+    fn chart(&self, name: &str, rows: &[(usize, String, f64)], precision: usize) -> BarChart {
+        let mut bars = Vec::with_capacity(rows.len());
+        let mut texts = Vec::with_capacity(rows.len());
+        for (argument, (index, label, _)) in rows.iter().enumerate() {
+            bars.push(Bar::new(argument as _, self.e[*index].unwrap_or_default()).name(label));
+            texts.push(format!(
+                "{label} {name}\n{}",
+                self.breakdown(*index, precision)
+            ));
+        }
+        BarChart::new(bars)
+            .name(name)
+            .element_formatter(Box::new(move |bar, _| {
+                texts
+                    .get(bar.argument as usize)
+                    .cloned()
+                    .unwrap_or_default()
+            }))
+    }
+}
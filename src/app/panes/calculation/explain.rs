@@ -0,0 +1,461 @@
+use super::{ID_SOURCE, Settings, state::State};
+use crate::{
+    app::{
+        computers::{CalculationComputed, CalculationKey},
+        panes::sample::field,
+    },
+    localization::localize,
+    utils::export,
+};
+use egui::{ComboBox, Id, RichText, ScrollArea, Ui};
+use egui_phosphor::regular::{COPY, FLOPPY_DISK};
+use lipid::fatty_acid::{
+    display::{COMMON, DisplayWithOptions},
+    polars::DataFrameExt as _,
+};
+use polars::prelude::*;
+use std::fmt::{self, Display, Formatter, Write as _};
+use tracing::error;
+
+const POSITIONS: [(&str, &str, &str); 2] = [
+    ("StereospecificNumber123", "SN123", "G1"),
+    ("StereospecificNumber2", "SN2", "G2"),
+];
+
+/// Explain view
+///
+/// Worked derivation of A-F for a row or of the scores for the totals, with
+/// the real numbers substituted.
+pub(super) struct ExplainView<'a> {
+    data_frame: &'a DataFrame,
+    hash: u64,
+    settings: &'a Settings,
+    state: &'a mut State,
+}
+
+impl<'a> ExplainView<'a> {
+    pub(super) fn new(
+        data_frame: &'a DataFrame,
+        hash: u64,
+        settings: &'a Settings,
+        state: &'a mut State,
+    ) -> Self {
+        Self {
+            data_frame,
+            hash,
+            settings,
+            state,
+        }
+    }
+}
+
+impl ExplainView<'_> {
+    pub(super) fn show(&mut self, ui: &mut Ui) {
+        if let Err(error) = self.try_show(ui) {
+            ui.label(error.to_string());
+        }
+    }
+
+    fn try_show(&mut self, ui: &mut Ui) -> PolarsResult<()> {
+        if self.data_frame.is_empty() {
+            return Ok(());
+        }
+        let target = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<CalculationComputed>()
+                .get(CalculationKey {
+                    data_frame: self.data_frame,
+                    hash: self.hash,
+                    settings: self.settings,
+                })
+        });
+        let fatty_acids = self.data_frame.fatty_acid();
+        let mut labels = Vec::with_capacity(fatty_acids.len());
+        for index in 0..fatty_acids.len() {
+            labels.push(
+                fatty_acids
+                    .get(index)?
+                    .map(|fatty_acid| format!("{:#}", (&fatty_acid).display(COMMON)))
+                    .unwrap_or_default(),
+            );
+        }
+        // Deleted rows fall back to the totals
+        if self.state.explain.is_some_and(|row| row >= labels.len()) {
+            self.state.explain = None;
+        }
+        let explanation = match self.state.explain {
+            Some(row) => Explanation::row(&target, &labels, row, self.settings)?,
+            None => Explanation::total(&target, &labels, self.settings)?,
+        };
+        ui.horizontal(|ui| {
+            ComboBox::from_id_salt(Id::new(ID_SOURCE).with("Explain"))
+                .selected_text(&explanation.title)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.state.explain, None, TOTAL);
+                    for (row, label) in labels.iter().enumerate() {
+                        ui.selectable_value(&mut self.state.explain, Some(row), label);
+                    }
+                });
+            ui.separator();
+            for format in [ExplainFormat::Text, ExplainFormat::AsciiDoc] {
+                if ui
+                    .button(format!("{COPY} {format}"))
+                    .on_hover_text(localize!("copy"))
+                    .clicked()
+                {
+                    ui.ctx().copy_text(explanation.format(format));
+                }
+            }
+            ui.separator();
+            for format in [ExplainFormat::Text, ExplainFormat::AsciiDoc] {
+                if ui
+                    .button(format!("{FLOPPY_DISK} {format}"))
+                    .on_hover_text(localize!("save"))
+                    .clicked()
+                {
+                    let name = format!("{}.{}", explanation.name(), format.extension());
+                    if let Err(error) = export(&name, explanation.format(format).as_bytes()) {
+                        error!(%error);
+                    }
+                }
+            }
+        });
+        ui.separator();
+        ScrollArea::both().show(ui, |ui| {
+            for section in &explanation.sections {
+                ui.label(RichText::new(&section.title).heading());
+                for line in &section.lines {
+                    ui.label(RichText::new(line).monospace());
+                }
+                ui.add_space(ui.spacing().item_spacing.y);
+            }
+        });
+        Ok(())
+    }
+}
+
+const TOTAL: &str = "∑";
+
+/// Explanation
+#[derive(Clone, Debug, Default)]
+struct Explanation {
+    title: String,
+    sections: Vec<Section>,
+}
+
+impl Explanation {
+    /// A-F of the row
+    fn row(
+        target: &DataFrame,
+        labels: &[String],
+        row: usize,
+        settings: &Settings,
+    ) -> PolarsResult<Self> {
+        let number = Number::new(settings.precision);
+        let mut sections = Vec::with_capacity(POSITIONS.len() + 1);
+        let mut es = Vec::with_capacity(POSITIONS.len());
+        for (position, name, score) in POSITIONS {
+            let data = |field_name| -> PolarsResult<Option<f64>> {
+                Ok(field(target, position, "Data", field_name)?[row])
+            };
+            let meta = |field_name| -> PolarsResult<Option<f64>> {
+                Ok(field(target, position, "Meta", field_name)?[row])
+            };
+            let mut lines = Vec::new();
+            match (
+                data("A")?,
+                data("B")?,
+                data("C")?,
+                data("D")?,
+                data("E")?,
+                data("F")?,
+                meta("Min")?,
+                meta("Max")?,
+                meta("Sum")?,
+            ) {
+                (
+                    Some(a),
+                    Some(b),
+                    Some(c),
+                    Some(d),
+                    Some(e),
+                    Some(f),
+                    Some(min),
+                    Some(max),
+                    Some(sum),
+                ) => {
+                    lines.push(format!(
+                        "{}: min = {}, median = {}, max = {}",
+                        localize!("reference"),
+                        number.show(min),
+                        number.show(d),
+                        number.show(max),
+                    ));
+                    lines.push(format!("B = {}", number.show(b)));
+                    lines.push(if b < min {
+                        format!(
+                            "B < min ⇒ A = min = {} ({})",
+                            number.show(a),
+                            localize!("out_of_range"),
+                        )
+                    } else if b > max {
+                        format!(
+                            "B > max ⇒ A = max = {} ({})",
+                            number.show(a),
+                            localize!("out_of_range"),
+                        )
+                    } else {
+                        format!("min ≤ B ≤ max ⇒ A = B = {}", number.show(a))
+                    });
+                    let mut line = if a != 0.0 {
+                        format!(
+                            "C = |A - B| / A = |{} - {}| / {} = {}",
+                            number.show(a),
+                            number.show(b),
+                            number.show(a),
+                            number.show(c),
+                        )
+                    } else {
+                        format!("A = 0 ⇒ C = {}", number.show(c))
+                    };
+                    if settings.round > 0 {
+                        write!(line, " ({} {})", localize!("round"), settings.round).ok();
+                    }
+                    lines.push(line);
+                    lines.push(format!(
+                        "D / ∑D = {} / {} = {}",
+                        number.show(d),
+                        number.show(sum),
+                        number.show(d / sum),
+                    ));
+                    lines.push(format!(
+                        "E = 50 * C * D / ∑D = 50 * {} * {} / {} = {}",
+                        number.show(c),
+                        number.show(d),
+                        number.show(sum),
+                        number.show(e),
+                    ));
+                    lines.push(format!(
+                        "{score} = 50 - ∑E = {} (-{})",
+                        number.show(f),
+                        number.show(e),
+                    ));
+                    es.push(Some(e));
+                }
+                (_, b, ..) => {
+                    lines.push(format!("B = {}", number.show_option(b)));
+                    lines.push(localize!("not_scored"));
+                    es.push(None);
+                }
+            }
+            sections.push(Section {
+                title: name.to_owned(),
+                lines,
+            });
+        }
+        let f = target["F"].f64()?;
+        let mut lines = Vec::new();
+        if let Some(value) = f.get(row) {
+            lines.push(format!(
+                "F = E(SN123) + E(SN2) = {} + {} = {}",
+                number.show_option(es[0]),
+                number.show_option(es[1]),
+                number.show(value),
+            ));
+            if let Some(sum) = f.sum() {
+                lines.push(format!(
+                    "G = 100 - ∑F = 100 - {} = {} (-{})",
+                    number.show(sum),
+                    number.show(100.0 - sum),
+                    number.show(value),
+                ));
+            }
+        } else {
+            lines.push(localize!("not_scored"));
+        }
+        sections.push(Section {
+            title: "F".to_owned(),
+            lines,
+        });
+        Ok(Self {
+            title: labels[row].clone(),
+            sections,
+        })
+    }
+
+    /// Scores of the totals
+    fn total(target: &DataFrame, labels: &[String], settings: &Settings) -> PolarsResult<Self> {
+        let number = Number::new(settings.precision);
+        let mut sections = Vec::with_capacity(POSITIONS.len() + 1);
+        let mut sums = Vec::with_capacity(POSITIONS.len());
+        for (position, name, score) in POSITIONS {
+            let d = field(target, position, "Meta", "Sum")?
+                .into_iter()
+                .flatten()
+                .next();
+            let es = field(target, position, "Data", "E")?;
+            let terms = terms(labels, &es, number);
+            let sum = es.iter().flatten().sum::<f64>();
+            sections.push(Section {
+                title: name.to_owned(),
+                lines: vec![
+                    format!("∑D = {}", number.show_option(d)),
+                    format!("∑E = {terms} = {}", number.show(sum)),
+                    format!(
+                        "{score} = 50 - ∑E = 50 - {} = {}",
+                        number.show(sum),
+                        number.show(50.0 - sum),
+                    ),
+                ],
+            });
+            sums.push(sum);
+        }
+        let fs = target["F"].f64()?.to_vec();
+        let terms = terms(labels, &fs, number);
+        let sum = fs.iter().flatten().sum::<f64>();
+        sections.push(Section {
+            title: "F".to_owned(),
+            lines: vec![
+                format!(
+                    "∑F = ∑E(SN123) + ∑E(SN2) = {} + {} = {}",
+                    number.show(sums[0]),
+                    number.show(sums[1]),
+                    number.show(sum),
+                ),
+                format!("∑F = {terms}"),
+                format!(
+                    "G = 100 - ∑F = 100 - {} = {}",
+                    number.show(sum),
+                    number.show(100.0 - sum),
+                ),
+            ],
+        });
+        Ok(Self {
+            title: TOTAL.to_owned(),
+            sections,
+        })
+    }
+
+    /// File name of the explanation
+    fn name(&self) -> String {
+        let mut name = String::from("explain");
+        if self.title != TOTAL {
+            name.push('.');
+            name.extend(self.title.chars().map(|char| match char {
+                ':' | '/' | '\\' | ' ' => '_',
+                _ => char,
+            }));
+        }
+        name
+    }
+
+    fn format(&self, format: ExplainFormat) -> String {
+        match format {
+            ExplainFormat::Text => self.text(),
+            ExplainFormat::AsciiDoc => self.asciidoc(),
+        }
+    }
+
+    fn text(&self) -> String {
+        let mut text = String::new();
+        writeln!(text, "{}", self.title).ok();
+        writeln!(text, "{}", "=".repeat(self.title.chars().count())).ok();
+        for section in &self.sections {
+            writeln!(text).ok();
+            writeln!(text, "{}", section.title).ok();
+            for line in &section.lines {
+                writeln!(text, "  {line}").ok();
+            }
+        }
+        text
+    }
+
+    fn asciidoc(&self) -> String {
+        let mut text = String::new();
+        writeln!(text, "= {}", self.title).ok();
+        for section in &self.sections {
+            writeln!(text).ok();
+            writeln!(text, "== {}", section.title).ok();
+            writeln!(text).ok();
+            writeln!(text, "[%hardbreaks]").ok();
+            for line in &section.lines {
+                writeln!(text, "pass:[{line}]").ok();
+            }
+        }
+        text
+    }
+}
+
+/// Section
+#[derive(Clone, Debug, Default)]
+struct Section {
+    title: String,
+    lines: Vec<String>,
+}
+
+/// Explanation format
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ExplainFormat {
+    Text,
+    AsciiDoc,
+}
+
+impl ExplainFormat {
+    const fn extension(&self) -> &'static str {
+        match self {
+            Self::Text => "txt",
+            Self::AsciiDoc => "adoc",
+        }
+    }
+}
+
+impl Display for ExplainFormat {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Text => f.write_str("Text"),
+            Self::AsciiDoc => f.write_str("AsciiDoc"),
+        }
+    }
+}
+
+/// Number formatter
+#[derive(Clone, Copy, Debug)]
+struct Number {
+    precision: usize,
+}
+
+impl Number {
+    const fn new(precision: usize) -> Self {
+        Self { precision }
+    }
+
+    fn show(&self, value: f64) -> String {
+        format!("{value:.*}", self.precision)
+    }
+
+    fn show_option(&self, value: Option<f64>) -> String {
+        value.map_or_else(|| "-".to_owned(), |value| self.show(value))
+    }
+}
+
+/// Non-zero terms of a sum labeled by fatty acids: `3.10 (16:0) + 0.64 (18:1)`
+fn terms(labels: &[String], values: &[Option<f64>], number: Number) -> String {
+    let terms = labels
+        .iter()
+        .zip(values)
+        .filter_map(|(label, value)| {
+            value
+                .filter(|value| *value != 0.0)
+                .map(|value| format!("{} ({label})", number.show(value)))
+        })
+        .collect::<Vec<_>>();
+    if terms.is_empty() {
+        number.show(0.0)
+    } else {
+        terms.join(" + ")
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,106 @@
+use super::*;
+use crate::{app::panes::sample::labels, presets::PRESETS, utils::hash_data_frame};
+
+const PRECISION: usize = 6;
+
+/// Numbers of the line as they are shown, the labels and the operators are
+/// skipped
+fn numbers(line: &str) -> Vec<String> {
+    line.split_whitespace()
+        .map(|token| token.trim_matches(|char| matches!(char, '(' | ')' | '|' | ',')))
+        .filter(|token| token.parse::<f64>().is_ok())
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+/// Per-row lines carry the A-F of the calculation for every preset
+#[test]
+fn row() -> PolarsResult<()> {
+    let settings = Settings {
+        precision: PRECISION,
+        ..Settings::new()
+    };
+    let show = |value: f64| Number::new(PRECISION).show(value);
+    for preset in PRESETS {
+        let title = preset.meta.title();
+        let target = CalculationComputed::default().get(CalculationKey {
+            data_frame: &preset.data,
+            hash: hash_data_frame(&preset.data),
+            settings: &settings,
+        });
+        let labels = labels(&preset.data)?
+            .into_iter()
+            .map(Option::unwrap_or_default)
+            .collect::<Vec<_>>();
+        let fs = target["F"].f64()?;
+        for row in 0..labels.len() {
+            let explanation = Explanation::row(&target, &labels, row, &settings)?;
+            assert_eq!(explanation.sections.len(), POSITIONS.len() + 1, "{title}");
+            let mut es = Vec::with_capacity(POSITIONS.len());
+            for ((position, name, _), section) in POSITIONS.into_iter().zip(&explanation.sections) {
+                let context = format!("{title} {} {name}", labels[row]);
+                assert_eq!(section.title, name, "{context}");
+                let data = |field_name| -> PolarsResult<Option<f64>> {
+                    Ok(field(&target, position, "Data", field_name)?[row])
+                };
+                let (Some(a), Some(b), Some(c), Some(d), Some(e), Some(f)) = (
+                    data("A")?,
+                    data("B")?,
+                    data("C")?,
+                    data("D")?,
+                    data("E")?,
+                    data("F")?,
+                ) else {
+                    assert_eq!(section.lines[1], localize!("not_scored"), "{context}");
+                    es.push(None);
+                    continue;
+                };
+                let sum = field(&target, position, "Meta", "Sum")?[row].unwrap();
+                let lines = &section.lines;
+                assert_eq!(lines.len(), 7, "{context}");
+                // B
+                assert_eq!(numbers(&lines[1]), [show(b)], "{context}");
+                // A
+                assert_eq!(numbers(&lines[2]).last(), Some(&show(a)), "{context}");
+                // C
+                let expected = if a != 0.0 {
+                    vec![show(a), show(b), show(a), show(c)]
+                } else {
+                    vec!["0".to_owned(), show(c)]
+                };
+                assert_eq!(numbers(&lines[3]), expected, "{context}");
+                // D
+                assert_eq!(
+                    numbers(&lines[4]),
+                    [show(d), show(sum), show(d / sum)],
+                    "{context}",
+                );
+                // E
+                assert_eq!(
+                    numbers(&lines[5]),
+                    ["50".to_owned(), show(c), show(d), show(sum), show(e)],
+                    "{context}",
+                );
+                // G1, G2
+                assert_eq!(
+                    numbers(&lines[6]),
+                    ["50".to_owned(), show(f), show(-e)],
+                    "{context}",
+                );
+                es.push(Some(e));
+            }
+            // F
+            let context = format!("{title} {} F", labels[row]);
+            let section = &explanation.sections[POSITIONS.len()];
+            match fs.get(row) {
+                Some(f) => assert_eq!(
+                    numbers(&section.lines[0]),
+                    [show(es[0].unwrap()), show(es[1].unwrap()), show(f)],
+                    "{context}",
+                ),
+                None => assert_eq!(section.lines, [localize!("not_scored")], "{context}"),
+            }
+        }
+    }
+    Ok(())
+}
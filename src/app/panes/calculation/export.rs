@@ -0,0 +1,169 @@
+use super::state::Selection;
+use crate::{
+    app::panes::sample::field,
+    utils::{Table, to_delimited},
+};
+use lipid::fatty_acid::{
+    display::{COMMON, DisplayWithOptions},
+    polars::DataFrameExt as _,
+};
+use polars::prelude::*;
+
+const POSITIONS: [(&str, &str); 2] = [
+    ("StereospecificNumber123", "SN123"),
+    ("StereospecificNumber2", "SN2"),
+];
+const FIELDS: [&str; 5] = ["A", "B", "C", "D", "E"];
+
+/// Calculated table flattened to readable headers
+///
+/// Columns are `Index`, `FA`, `Experimental SN123`, `Experimental SN2`,
+/// `SN123 A`…`SN123 E`, `SN2 A`…`SN2 E` and `F`. Two rows of totals follow
+/// the body, the sums (`∑`) and the scores (`G`: 50 - ∑E per position and
+/// 100 - ∑F).
+pub(crate) fn flatten(data_frame: &DataFrame, target: &DataFrame) -> PolarsResult<DataFrame> {
+    let fatty_acids = data_frame.fatty_acid();
+    let mut labels = Vec::with_capacity(fatty_acids.len());
+    for index in 0..fatty_acids.len() {
+        labels.push(
+            fatty_acids
+                .get(index)?
+                .map(|fatty_acid| format!("{:#}", (&fatty_acid).display(COMMON))),
+        );
+    }
+    let mut body = vec![
+        target["Index"].clone(),
+        Column::new("FA".into(), labels),
+        data_frame["StereospecificNumber123"]
+            .clone()
+            .with_name("Experimental SN123".into()),
+        data_frame["StereospecificNumber2"]
+            .clone()
+            .with_name("Experimental SN2".into()),
+    ];
+    let mut totals = vec![
+        Column::new("Index".into(), [None::<IdxSize>, None]).cast(target["Index"].dtype())?,
+        Column::new("FA".into(), [Some("∑"), Some("G")]),
+        Column::new(
+            "Experimental SN123".into(),
+            [data_frame["StereospecificNumber123"].f64()?.sum(), None],
+        ),
+        Column::new(
+            "Experimental SN2".into(),
+            [data_frame["StereospecificNumber2"].f64()?.sum(), None],
+        ),
+    ];
+    for (position, short) in POSITIONS {
+        for name in FIELDS {
+            let values = field(target, position, "Data", name)?;
+            let total = match name {
+                "D" => [
+                    field(target, position, "Meta", "Sum")?
+                        .into_iter()
+                        .flatten()
+                        .next(),
+                    None,
+                ],
+                "E" => {
+                    let sum = values.iter().flatten().sum::<f64>();
+                    [Some(sum), Some(50.0 - sum)]
+                }
+                _ => [None, None],
+            };
+            body.push(Column::new(format!("{short} {name}").into(), values));
+            totals.push(Column::new(format!("{short} {name}").into(), total));
+        }
+    }
+    let f = target["F"].f64()?;
+    body.push(target["F"].clone());
+    totals.push(Column::new(
+        "F".into(),
+        [f.sum(), f.sum().map(|sum| 100.0 - sum)],
+    ));
+    let mut data_frame = DataFrame::new(body)?;
+    data_frame.vstack_mut(&DataFrame::new(totals)?)?;
+    Ok(data_frame)
+}
+
+/// Calculated table with the grouped header of the table view
+pub(crate) fn table(
+    data_frame: &DataFrame,
+    target: &DataFrame,
+    precision: usize,
+) -> PolarsResult<Table> {
+    let mut names = vec!["Index", "FA", "SN123", "SN2"];
+    names.extend(FIELDS);
+    names.extend(FIELDS);
+    names.push("F");
+    Ok(Table {
+        groups: vec![
+            ("ID".to_owned(), 2),
+            ("Experimental".to_owned(), 2),
+            ("SN123".to_owned(), FIELDS.len()),
+            ("SN2".to_owned(), FIELDS.len()),
+            (String::new(), 1),
+        ],
+        names: names.into_iter().map(ToOwned::to_owned).collect(),
+        rows: Table::rows(&flatten(data_frame, target)?, precision)?,
+    })
+}
+
+/// Calculated table with its input
+///
+/// Columns are `Index`, `FattyAcid`, `Experimental` (`StereospecificNumber123`,
+/// `StereospecificNumber2`), the calculated `StereospecificNumber123`,
+/// `StereospecificNumber2` (`Data`, `Meta`) and `F`.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn results(data_frame: &DataFrame, target: &DataFrame) -> PolarsResult<DataFrame> {
+    let experimental = StructChunked::from_series(
+        "Experimental".into(),
+        data_frame.height(),
+        [
+            data_frame["StereospecificNumber123"].as_materialized_series(),
+            data_frame["StereospecificNumber2"].as_materialized_series(),
+        ]
+        .into_iter(),
+    )?;
+    DataFrame::new(vec![
+        target["Index"].clone(),
+        data_frame["FattyAcid"].clone(),
+        experimental.into_column(),
+        target["StereospecificNumber123"].clone(),
+        target["StereospecificNumber2"].clone(),
+        target["F"].clone(),
+    ])
+}
+
+/// Tab separated cells of the flattened table with a header line
+///
+/// The selection is in table coordinates, its footer row (the height of the
+/// source) spans both rows of totals. Without a selection the whole table is
+/// copied.
+pub(crate) fn copy(
+    data_frame: &DataFrame,
+    height: usize,
+    selection: Option<Selection>,
+    precision: usize,
+) -> PolarsResult<String> {
+    let data_frame = match selection {
+        Some(selection) => {
+            let rows = selection.rows();
+            let start = (*rows.start()).min(data_frame.height());
+            let end = if *rows.end() < height {
+                *rows.end() + 1
+            } else {
+                data_frame.height()
+            };
+            let names = data_frame
+                .get_column_names_owned()
+                .into_iter()
+                .skip(*selection.columns().start())
+                .take(selection.columns().count());
+            data_frame
+                .slice(start as _, end.saturating_sub(start))
+                .select(names)?
+        }
+        None => data_frame.clone(),
+    };
+    to_delimited(&data_frame, '\t', Some(precision))
+}
@@ -0,0 +1,186 @@
+use super::{
+    ID_SOURCE, Settings,
+    state::{Goal, State},
+};
+use crate::{
+    app::{
+        computers::{CalculationComputed, CalculationKey, GoalSeekComputed, GoalSeekKey},
+        panes::sample::scores,
+        widgets::FloatWidget,
+    },
+    localization::localize,
+};
+use egui::{DragValue, Grid, Id, RichText, ScrollArea, Ui, Widget, util::hash};
+use lipid::fatty_acid::{
+    display::{COMMON, DisplayWithOptions},
+    polars::DataFrameExt as _,
+};
+use polars::prelude::*;
+
+/// Goal seek view
+pub(super) struct GoalSeekView<'a> {
+    data_frame: &'a DataFrame,
+    hash: u64,
+    settings: &'a Settings,
+    state: &'a mut State,
+}
+
+impl<'a> GoalSeekView<'a> {
+    pub(super) fn new(
+        data_frame: &'a DataFrame,
+        hash: u64,
+        settings: &'a Settings,
+        state: &'a mut State,
+    ) -> Self {
+        Self {
+            data_frame,
+            hash,
+            settings,
+            state,
+        }
+    }
+}
+
+impl GoalSeekView<'_> {
+    pub(super) fn show(&mut self, ui: &mut Ui) {
+        if let Err(error) = self.try_show(ui) {
+            ui.label(error.to_string());
+        }
+    }
+
+    fn try_show(&mut self, ui: &mut Ui) -> PolarsResult<()> {
+        if self.data_frame.is_empty() {
+            return Ok(());
+        }
+        let target = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<CalculationComputed>()
+                .get(CalculationKey {
+                    data_frame: self.data_frame,
+                    hash: self.hash,
+                    settings: self.settings,
+                })
+        });
+        let score = scores(&target)?[2];
+        self.goal(ui, score);
+        let data_frame = ui.memory_mut(|memory| {
+            memory.caches.cache::<GoalSeekComputed>().get(GoalSeekKey {
+                data_frame: &target,
+                hash: hash((self.hash, self.settings)),
+                target: self.state.goal.target(),
+            })
+        });
+        let fatty_acids = self.data_frame.fatty_acid();
+        let indices = data_frame["Index"].u32()?;
+        let positions = data_frame["Position"].str()?;
+        let values = [&data_frame["Value"], &data_frame["Min"], &data_frame["Max"]];
+        let gains = [&data_frame["Gain"], &data_frame["Score"]];
+        let targets = data_frame["Target"].f64()?;
+        let stereospecific_numbers123 = self.data_frame["StereospecificNumber123"].f64()?;
+        let precision = self.settings.precision;
+        ScrollArea::both()
+            .show(ui, |ui| -> PolarsResult<()> {
+                Grid::new(Id::new(ID_SOURCE).with("GoalSeek"))
+                    .striped(true)
+                    .show(ui, |ui| -> PolarsResult<()> {
+                        ui.heading("#");
+                        ui.heading(localize!("fatty_acid.abbreviation"));
+                        ui.heading("SN");
+                        ui.heading("B").on_hover_text(localize!("value"));
+                        ui.heading("Min");
+                        ui.heading("Max");
+                        ui.heading(localize!("gain"))
+                            .on_hover_text(localize!("gain.description"));
+                        ui.heading(localize!("score"))
+                            .on_hover_text(localize!("cumulative_score"));
+                        ui.heading(localize!("target"))
+                            .on_hover_text(localize!("target.description"));
+                        ui.end_row();
+                        for row in 0..data_frame.height() {
+                            let required = targets.get(row).is_some();
+                            ui.label((row + 1).to_string());
+                            let label = match indices.get(row) {
+                                Some(index) => fatty_acids
+                                    .get(index as _)?
+                                    .map(|fatty_acid| {
+                                        format!("{:#}", (&fatty_acid).display(COMMON))
+                                    })
+                                    .unwrap_or_default(),
+                                None => String::new(),
+                            };
+                            let mut label = RichText::new(label);
+                            if required {
+                                label = label.strong();
+                            } else {
+                                label = label.weak();
+                            }
+                            ui.label(label);
+                            ui.label(match positions.get(row) {
+                                Some("StereospecificNumber123") => "1,2,3",
+                                Some("StereospecificNumber2") => "2",
+                                _ => "",
+                            });
+                            // Relative sn-2 back to the absolute: SN2 = 3 * SN123 * relative / 100
+                            let scale = match positions.get(row) {
+                                Some("StereospecificNumber2") if self.settings.relative => indices
+                                    .get(row)
+                                    .and_then(|index| stereospecific_numbers123.get(index as _))
+                                    .map(|stereospecific_number123| {
+                                        3.0 * stereospecific_number123 / 100.0
+                                    }),
+                                _ => Some(1.0),
+                            };
+                            let absolute = |value: Option<f64>| Some(value? * scale?);
+                            for column in values {
+                                FloatWidget::new(|| Ok(absolute(column.f64()?.get(row))))
+                                    .precision(Some(precision))
+                                    .hover()
+                                    .ui(ui);
+                            }
+                            for column in gains {
+                                FloatWidget::new(|| Ok(column.f64()?.get(row)))
+                                    .precision(Some(precision))
+                                    .hover()
+                                    .ui(ui);
+                            }
+                            FloatWidget::new(|| Ok(absolute(targets.get(row))))
+                                .precision(Some(precision))
+                                .hover()
+                                .ui(ui);
+                            ui.end_row();
+                        }
+                        Ok(())
+                    })
+                    .inner
+            })
+            .inner
+    }
+
+    fn goal(&mut self, ui: &mut Ui, score: Option<f64>) {
+        ui.horizontal(|ui| {
+            ui.label(localize!("score"));
+            FloatWidget::new(|| Ok(score))
+                .precision(Some(self.settings.precision))
+                .hover()
+                .ui(ui);
+            ui.separator();
+            ui.label(localize!("goal"));
+            ui.radio_value(
+                &mut self.state.goal,
+                Goal::Zero,
+                localize!("zero_deviation"),
+            )
+            .on_hover_text(localize!("zero_deviation.description"));
+            let score = match self.state.goal {
+                Goal::Score(score) => score,
+                Goal::Zero => score.unwrap_or(100.0).ceil().min(100.0),
+            };
+            ui.radio_value(&mut self.state.goal, Goal::Score(score), localize!("score"));
+            if let Goal::Score(score) = &mut self.state.goal {
+                DragValue::new(score).range(0.0..=100.0).speed(0.1).ui(ui);
+            }
+        });
+        ui.separator();
+    }
+}
@@ -1,11 +1,43 @@
-use self::{settings::Settings, state::State, table::TableView};
-use crate::{localization::localize, utils::save};
+pub(crate) use self::export::{flatten, table};
+
+#[cfg(not(target_arch = "wasm32"))]
+use self::export::results;
+use self::{
+    composition::CompositionView,
+    contribution::ContributionView,
+    explain::ExplainView,
+    export::copy,
+    goal_seek::GoalSeekView,
+    problems::ProblemsView,
+    reference::ReferenceView,
+    selectivity::SelectivityView,
+    sensitivity::SensitivityView,
+    settings::Settings,
+    state::{State, View},
+    table::TableView,
+};
+use super::Sample;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::utils::{key_value_metadata, write_parquet};
+use crate::{
+    app::{
+        computers::{CalculationComputed, CalculationKey, ValidationComputed, ValidationKey},
+        report,
+    },
+    localization::localize,
+    utils::{Format, Markup, export, hash_data_frame, save, to_delimited},
+};
 use anyhow::Result;
-use egui::{CursorIcon, Response, RichText, ScrollArea, Ui, Window, menu::bar, util::hash};
+use egui::{
+    CollapsingHeader, CursorIcon, Id, Response, RichText, ScrollArea, Ui, Window, menu::bar,
+};
 use egui_phosphor::regular::{
-    ARROWS_CLOCKWISE, ARROWS_HORIZONTAL, ERASER, FLOPPY_DISK, GEAR, NOTE_PENCIL, PENCIL, TAG,
+    ARROWS_CLOCKWISE, ARROWS_HORIZONTAL, ATOM, CHART_BAR, CHART_BAR_HORIZONTAL, COPY, ERASER,
+    EXPORT, FLOPPY_DISK, GEAR, MATH_OPERATIONS, NOTE_PENCIL, PENCIL, RULER, SCALES, TABLE, TAG,
+    TARGET, WARNING,
 };
 use metadata::MetaDataFrame;
+use polars::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::fmt::Write;
 use tracing::error;
@@ -37,6 +69,18 @@ impl Pane {
         self.frame.meta.title()
     }
 
+    pub(crate) fn sample(&self) -> Sample {
+        Sample {
+            title: self.title(),
+            frame: self.frame.clone(),
+            hash: self
+                .state
+                .hash
+                .unwrap_or_else(|| hash_data_frame(&self.frame.data)),
+            settings: self.settings.clone(),
+        }
+    }
+
     pub(crate) fn header(&mut self, ui: &mut Ui) -> Response {
         bar(ui, |ui| {
             ScrollArea::horizontal()
@@ -85,6 +129,7 @@ impl Pane {
                     .clicked()
                 {
                     self.frame.data = self.frame.data.clear();
+                    self.state.hash = None;
                 }
             },
         );
@@ -96,16 +141,139 @@ impl Pane {
         )
         .on_hover_text(localize!("settings"));
         ui.separator();
-        if ui
-            .button(RichText::new(FLOPPY_DISK).heading())
-            .on_hover_text(localize!("save"))
-            .on_hover_text(&self.settings.label)
-            .clicked()
-        {
-            if let Err(error) = self.save() {
-                error!(%error);
+        // View
+        ui.selectable_value(
+            &mut self.state.view,
+            View::Table,
+            RichText::new(TABLE).heading(),
+        )
+        .on_hover_text(localize!("table"));
+        ui.selectable_value(
+            &mut self.state.view,
+            View::Composition,
+            RichText::new(ATOM).heading(),
+        )
+        .on_hover_text(localize!("mass_composition"));
+        ui.selectable_value(
+            &mut self.state.view,
+            View::Selectivity,
+            RichText::new(SCALES).heading(),
+        )
+        .on_hover_text(localize!("selectivity_factor"));
+        ui.selectable_value(
+            &mut self.state.view,
+            View::Contribution,
+            RichText::new(CHART_BAR).heading(),
+        )
+        .on_hover_text(localize!("contribution"));
+        ui.selectable_value(
+            &mut self.state.view,
+            View::Reference,
+            RichText::new(RULER).heading(),
+        )
+        .on_hover_text(localize!("reference_range"));
+        ui.selectable_value(
+            &mut self.state.view,
+            View::GoalSeek,
+            RichText::new(TARGET).heading(),
+        )
+        .on_hover_text(localize!("goal_seek"));
+        ui.selectable_value(
+            &mut self.state.view,
+            View::Sensitivity,
+            RichText::new(CHART_BAR_HORIZONTAL).heading(),
+        )
+        .on_hover_text(localize!("sensitivity"));
+        ui.selectable_value(
+            &mut self.state.view,
+            View::Explain,
+            RichText::new(MATH_OPERATIONS).heading(),
+        )
+        .on_hover_text(localize!("explain"))
+        .on_hover_text(localize!("explain.description"));
+        ui.separator();
+        // Save
+        ui.menu_button(RichText::new(FLOPPY_DISK).heading(), |ui| {
+            for format in Format::ALL {
+                if ui
+                    .button(format.text())
+                    .on_hover_text(format!("{}.hmf.{}", self.name(), format.extension()))
+                    .clicked()
+                {
+                    if let Err(error) = self.save(format) {
+                        error!(%error);
+                    }
+                    ui.close_menu();
+                }
             }
-        }
+        })
+        .response
+        .on_hover_text(localize!("save"))
+        .on_hover_text(localize!("save.description"));
+        // Copy
+        ui.menu_button(RichText::new(COPY).heading(), |ui| {
+            if ui.button(localize!("table")).clicked() {
+                if let Err(error) = self.copy(ui, false) {
+                    error!(%error);
+                }
+                ui.close_menu();
+            }
+            ui.add_enabled_ui(self.state.selection.is_some(), |ui| {
+                if ui
+                    .button(localize!("selection"))
+                    .on_hover_text(localize!("selection.description"))
+                    .clicked()
+                {
+                    if let Err(error) = self.copy(ui, true) {
+                        error!(%error);
+                    }
+                    ui.close_menu();
+                }
+            });
+        })
+        .response
+        .on_hover_text(localize!("copy"))
+        .on_hover_text(localize!("selection.description"));
+        // Export
+        ui.menu_button(RichText::new(EXPORT).heading(), |ui| {
+            for (text, delimiter, extension) in [("CSV", ',', "csv"), ("TSV", '\t', "tsv")] {
+                if ui.button(text).clicked() {
+                    if let Err(error) = self.export(ui, delimiter, extension) {
+                        error!(%error);
+                    }
+                    ui.close_menu();
+                }
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            if ui.button("Parquet").clicked() {
+                if let Err(error) = self.export_parquet(ui) {
+                    error!(%error);
+                }
+                ui.close_menu();
+            }
+            ui.separator();
+            if ui
+                .button("HTML")
+                .on_hover_text(localize!("report"))
+                .clicked()
+            {
+                if let Err(error) = self.export_report(ui) {
+                    error!(%error);
+                }
+                ui.close_menu();
+            }
+            for markup in Markup::ALL {
+                if ui.button(markup.text()).clicked() {
+                    if let Err(error) = self.export_markup(ui, markup) {
+                        error!(%error);
+                    }
+                    ui.close_menu();
+                }
+            }
+        })
+        .response
+        .on_hover_text(localize!("export"))
+        .on_hover_text(localize!("export.description"));
         response
     }
 
@@ -114,7 +282,17 @@ impl Pane {
         if self.settings.editable {
             self.body_content_meta(ui);
         }
-        self.body_content_data(ui);
+        self.body_content_problems(ui);
+        match self.state.view {
+            View::Table => self.body_content_data(ui),
+            View::Composition => self.body_content_composition(ui),
+            View::Selectivity => self.body_content_selectivity(ui),
+            View::Contribution => self.body_content_contribution(ui),
+            View::Reference => self.body_content_reference(ui),
+            View::GoalSeek => self.body_content_goal_seek(ui),
+            View::Sensitivity => self.body_content_sensitivity(ui),
+            View::Explain => self.body_content_explain(ui),
+        }
     }
 
     fn body_content_meta(&mut self, ui: &mut Ui) {
@@ -124,21 +302,200 @@ impl Pane {
         });
     }
 
+    /// Problems of the input, shown if there are any
+    fn body_content_problems(&mut self, ui: &mut Ui) {
+        let hash = self.hash();
+        let problems = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<ValidationComputed>()
+                .get(ValidationKey {
+                    data_frame: &self.frame.data,
+                    hash,
+                })
+        });
+        if problems.is_empty() {
+            return;
+        }
+        ui.style_mut().visuals.collapsing_header_frame = true;
+        CollapsingHeader::new(
+            RichText::new(format!(
+                "{WARNING} {} ({})",
+                localize!("problems"),
+                problems.len(),
+            ))
+            .heading(),
+        )
+        .id_salt(Id::new(ID_SOURCE).with("Problems"))
+        .default_open(true)
+        .show(ui, |ui| {
+            ScrollArea::vertical()
+                .id_salt(Id::new(ID_SOURCE).with("ProblemsScroll"))
+                .max_height(ui.available_height() / 4.0)
+                .show(ui, |ui| {
+                    ProblemsView::new(
+                        &self.frame.data,
+                        &problems,
+                        self.settings.precision,
+                        &mut self.state,
+                    )
+                    .show(ui);
+                });
+        })
+        .header_response
+        .on_hover_text(localize!("problems.description"));
+    }
+
     fn body_content_data(&mut self, ui: &mut Ui) {
-        TableView::new(&mut self.frame.data, &self.settings, &mut self.state).show(ui);
+        let hash = self.hash();
+        TableView::new(&mut self.frame.data, hash, &self.settings, &mut self.state).show(ui);
+    }
+
+    fn body_content_composition(&mut self, ui: &mut Ui) {
+        let hash = self.hash();
+        CompositionView::new(&self.frame.data, hash, &self.settings).show(ui);
+    }
+
+    fn body_content_selectivity(&mut self, ui: &mut Ui) {
+        let hash = self.hash();
+        SelectivityView::new(&self.frame.data, hash, &self.settings).show(ui);
+    }
+
+    fn body_content_contribution(&mut self, ui: &mut Ui) {
+        let hash = self.hash();
+        ContributionView::new(&self.frame.data, hash, &self.settings).show(ui);
+    }
+
+    fn body_content_reference(&mut self, ui: &mut Ui) {
+        let hash = self.hash();
+        ReferenceView::new(&self.frame.data, hash, &self.settings).show(ui);
     }
 
-    pub(super) fn hash(&self) -> u64 {
-        hash(&self.frame)
+    fn body_content_goal_seek(&mut self, ui: &mut Ui) {
+        let hash = self.hash();
+        GoalSeekView::new(&self.frame.data, hash, &self.settings, &mut self.state).show(ui);
     }
 
-    fn save(&mut self) -> Result<()> {
+    fn body_content_sensitivity(&mut self, ui: &mut Ui) {
+        let hash = self.hash();
+        SensitivityView::new(&self.frame.data, hash, &self.settings, &mut self.state).show(ui);
+    }
+
+    fn body_content_explain(&mut self, ui: &mut Ui) {
+        let hash = self.hash();
+        ExplainView::new(&self.frame.data, hash, &self.settings, &mut self.state).show(ui);
+    }
+
+    /// Appends rows to the data
+    pub(crate) fn append(&mut self, data_frame: &DataFrame) -> PolarsResult<()> {
+        if self.frame.data.width() == 0 {
+            self.frame.data = data_frame.clone();
+        } else {
+            let names = self.frame.data.get_column_names_owned();
+            self.frame.data.vstack_mut(&data_frame.select(names)?)?;
+        }
+        self.frame.data.as_single_chunk_par();
+        self.state.hash = None;
+        Ok(())
+    }
+
+    /// Content hash of the data, computed once per edit
+    pub(crate) fn hash(&mut self) -> u64 {
+        *self
+            .state
+            .hash
+            .get_or_insert_with(|| hash_data_frame(&self.frame.data))
+    }
+
+    /// File name without an extension
+    fn name(&self) -> String {
         let mut name = self.frame.meta.name.replace(" ", "_");
         if let Some(version) = &self.frame.meta.version {
-            write!(name, ".{version}")?;
+            write!(name, ".{version}").ok();
         }
-        name.push_str(".hmf.ipc");
-        save(&name, &mut self.frame)?;
+        name
+    }
+
+    fn save(&mut self, format: Format) -> Result<()> {
+        let name = format!("{}.hmf.{}", self.name(), format.extension());
+        save(&name, &mut self.frame, format)?;
+        Ok(())
+    }
+
+    /// Calculated table (cached)
+    fn target(&mut self, ui: &Ui) -> DataFrame {
+        let hash = self.hash();
+        ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<CalculationComputed>()
+                .get(CalculationKey {
+                    data_frame: &self.frame.data,
+                    hash,
+                    settings: &self.settings,
+                })
+        })
+    }
+
+    /// Calculated table flattened to readable headers
+    fn flatten(&mut self, ui: &Ui) -> PolarsResult<DataFrame> {
+        let target = self.target(ui);
+        flatten(&self.frame.data, &target)
+    }
+
+    /// Copies the calculated table or the selected cells as tab separated text
+    fn copy(&mut self, ui: &Ui, selection: bool) -> Result<()> {
+        let data_frame = self.flatten(ui)?;
+        let selection = self.state.selection.filter(|_| selection);
+        let text = copy(
+            &data_frame,
+            self.frame.data.height(),
+            selection,
+            self.settings.precision,
+        )?;
+        ui.ctx().copy_text(text);
+        Ok(())
+    }
+
+    /// Exports the calculated table as delimited text
+    fn export(&mut self, ui: &Ui, delimiter: char, extension: &str) -> Result<()> {
+        let data_frame = self.flatten(ui)?;
+        let text = to_delimited(&data_frame, delimiter, None)?;
+        export(&format!("{}.{extension}", self.name()), text.as_bytes())?;
+        Ok(())
+    }
+
+    /// Exports the HTML report
+    fn export_report(&mut self, ui: &Ui) -> Result<()> {
+        let target = self.target(ui);
+        let html = report::sample(
+            &self.frame,
+            &target,
+            self.settings.precision,
+            env!("CARGO_PKG_VERSION"),
+        )?;
+        export(&format!("{}.html", self.name()), html.as_bytes())?;
+        Ok(())
+    }
+
+    /// Exports the calculated table as an AsciiDoc or Markdown table
+    fn export_markup(&mut self, ui: &Ui, markup: Markup) -> Result<()> {
+        let target = self.target(ui);
+        let text = table(&self.frame.data, &target, self.settings.precision)?.format(markup);
+        export(
+            &format!("{}.{}", self.name(), markup.extension()),
+            text.as_bytes(),
+        )?;
+        Ok(())
+    }
+
+    /// Exports the calculated table with its input and metadata as Parquet
+    #[cfg(not(target_arch = "wasm32"))]
+    fn export_parquet(&mut self, ui: &Ui) -> Result<()> {
+        let target = self.target(ui);
+        let mut data_frame = results(&self.frame.data, &target)?;
+        let bytes = write_parquet(&mut data_frame, key_value_metadata(&self.frame))?;
+        export(&format!("{}.results.parquet", self.name()), &bytes)?;
         Ok(())
     }
 
@@ -148,18 +505,18 @@ impl Pane {
             .open(&mut self.state.open_settings_window)
             .show(ui.ctx(), |ui| self.settings.show(ui));
     }
-
-    // fn save(&self) -> Result<()> {
-    //     let contents = ron::ser::to_string_pretty(
-    //         &self.frame.data,
-    //         PrettyConfig::new().extensions(Extensions::IMPLICIT_SOME | Extensions::UNWRAP_NEWTYPES),
-    //     )?;
-    //     std::fs::write(format!("{}.hmf.ron", self.settings.label), contents)?;
-    //     Ok(())
-    // }
 }
 
 pub(crate) mod settings;
 
+mod composition;
+mod contribution;
+mod explain;
+mod export;
+mod goal_seek;
+mod problems;
+mod reference;
+mod selectivity;
+mod sensitivity;
 mod state;
 mod table;
@@ -0,0 +1,110 @@
+use super::{
+    ID_SOURCE,
+    state::{Selection, State, View},
+    table::column,
+};
+use crate::{
+    app::computers::{Problem, ProblemField, ProblemKind, TOLERANCE},
+    localization::localize,
+};
+use egui::{Grid, Id, Ui};
+use lipid::fatty_acid::{
+    display::{COMMON, DisplayWithOptions},
+    polars::DataFrameExt as _,
+};
+use polars::prelude::*;
+
+/// Problems view
+///
+/// Problems of the input, a click on a row selects its cell in the table.
+pub(super) struct ProblemsView<'a> {
+    data_frame: &'a DataFrame,
+    problems: &'a [Problem],
+    precision: usize,
+    state: &'a mut State,
+}
+
+impl<'a> ProblemsView<'a> {
+    pub(super) fn new(
+        data_frame: &'a DataFrame,
+        problems: &'a [Problem],
+        precision: usize,
+        state: &'a mut State,
+    ) -> Self {
+        Self {
+            data_frame,
+            problems,
+            precision,
+            state,
+        }
+    }
+}
+
+impl ProblemsView<'_> {
+    pub(super) fn show(&mut self, ui: &mut Ui) {
+        if let Err(error) = self.try_show(ui) {
+            ui.label(error.to_string());
+        }
+    }
+
+    fn try_show(&mut self, ui: &mut Ui) -> PolarsResult<()> {
+        let fatty_acids = self.data_frame.fatty_acid();
+        Grid::new(Id::new(ID_SOURCE).with("ProblemsGrid"))
+            .striped(true)
+            .show(ui, |ui| -> PolarsResult<()> {
+                for problem in self.problems {
+                    let row = if problem.row < self.data_frame.height() {
+                        let fatty_acid = fatty_acids
+                            .get(problem.row)?
+                            .map(|fatty_acid| format!("{:#}", (&fatty_acid).display(COMMON)))
+                            .unwrap_or_default();
+                        format!("{} {fatty_acid}", problem.row)
+                    } else {
+                        localize!("totals")
+                    };
+                    if ui.link(row).clicked() {
+                        self.state.view = View::Table;
+                        self.state.selection =
+                            Some(Selection::new(problem.row, column(problem.field)));
+                        self.state.scroll_to_row = Some(problem.row);
+                    }
+                    ui.label(match problem.field {
+                        ProblemField::FattyAcid => "FA",
+                        ProblemField::StereospecificNumber123 => "SN123",
+                        ProblemField::StereospecificNumber2 => "SN2",
+                    });
+                    ui.label(text(&problem.kind, self.precision));
+                    ui.end_row();
+                }
+                Ok(())
+            })
+            .inner
+    }
+}
+
+/// Text of the problem
+pub(super) fn text(kind: &ProblemKind, precision: usize) -> String {
+    match *kind {
+        ProblemKind::Negative(value) => {
+            format!("{}: {value:.precision$}", localize!("negative_value"))
+        }
+        ProblemKind::NotANumber => localize!("not_a_number"),
+        ProblemKind::Duplicate(row) => format!("{} {row}", localize!("duplicate_of_row")),
+        ProblemKind::Sum(sum) => {
+            format!(
+                "{}: {sum:.precision$} ∉ [{}, {}]",
+                localize!("sum_far_from_100"),
+                100.0 - TOLERANCE,
+                100.0 + TOLERANCE,
+            )
+        }
+        ProblemKind::Relative(relative) => {
+            format!(
+                "{}: {relative:.precision$} %",
+                localize!("relative_above_100")
+            )
+        }
+        ProblemKind::WithoutTotal => localize!("sn2_without_sn123"),
+        ProblemKind::Index(index) => format!("{}: {index}", localize!("index_beyond_chain")),
+    }
+}
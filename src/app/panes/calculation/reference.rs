@@ -0,0 +1,194 @@
+use super::{ID_SOURCE, Settings};
+use crate::{
+    app::computers::{ReferenceComputed, ReferenceKey},
+    localization::localize,
+};
+use egui::{Id, Ui};
+use egui_plot::{BoxElem, BoxPlot, BoxSpread, Legend, MarkerShape, Plot, Points};
+use lipid::fatty_acid::{
+    display::{COMMON, DisplayWithOptions},
+    polars::DataFrameExt as _,
+};
+use polars::prelude::*;
+
+/// Reference range view
+pub(super) struct ReferenceView<'a> {
+    data_frame: &'a DataFrame,
+    hash: u64,
+    settings: &'a Settings,
+}
+
+impl<'a> ReferenceView<'a> {
+    pub(super) fn new(data_frame: &'a DataFrame, hash: u64, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            hash,
+            settings,
+        }
+    }
+}
+
+impl ReferenceView<'_> {
+    pub(super) fn show(&self, ui: &mut Ui) {
+        if let Err(error) = self.try_show(ui) {
+            ui.label(error.to_string());
+        }
+    }
+
+    fn try_show(&self, ui: &mut Ui) -> PolarsResult<()> {
+        if self.data_frame.is_empty() {
+            return Ok(());
+        }
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<ReferenceComputed>()
+                .get(ReferenceKey {
+                    data_frame: self.data_frame,
+                    hash: self.hash,
+                    settings: self.settings,
+                })
+        });
+        let fatty_acids = data_frame.fatty_acid();
+        let mut labels = Vec::with_capacity(fatty_acids.len());
+        for index in 0..fatty_acids.len() {
+            labels.push(
+                fatty_acids
+                    .get(index)?
+                    .map(|fatty_acid| format!("{:#}", (&fatty_acid).display(COMMON)))
+                    .unwrap_or_default(),
+            );
+        }
+        let height = ui.available_height() / 2.0;
+        for (name, column) in [
+            ("SN123", "StereospecificNumber123"),
+            ("SN2", "StereospecificNumber2"),
+        ] {
+            let position = Position::new(&data_frame, column)?;
+            self.plot(ui, name, &labels, &position, height);
+        }
+        Ok(())
+    }
+
+    fn plot(&self, ui: &mut Ui, name: &str, labels: &[String], position: &Position, height: f32) {
+        let precision = self.settings.precision;
+        let mut boxes = Vec::with_capacity(labels.len());
+        let mut inside = Vec::new();
+        let mut outside = Vec::new();
+        let mut unreferenced = Vec::new();
+        for (index, label) in labels.iter().enumerate() {
+            let argument = index as f64;
+            if let (Some(median), Some(min), Some(max)) = (
+                position.median[index],
+                position.min[index],
+                position.max[index],
+            ) {
+                // The reference holds the width of the interquartile range but
+                // not its quartiles, so the box is collapsed to the median
+                let spread = BoxSpread::new(min, median, median, median, max);
+                let mut name = label.clone();
+                if let Some(interquartile_range) = position.interquartile_range[index] {
+                    name = format!("{name}\nIQR = {interquartile_range:.precision$}");
+                }
+                boxes.push(BoxElem::new(argument, spread).name(name));
+                if let Some(value) = position.value[index] {
+                    if value < min || value > max {
+                        outside.push([argument, value]);
+                    } else {
+                        inside.push([argument, value]);
+                    }
+                }
+            } else if let Some(value) = position.value[index] {
+                unreferenced.push([argument, value]);
+            }
+        }
+        let labels = labels.to_vec();
+        let color = ui.visuals().error_fg_color;
+        let weak = ui.visuals().weak_text_color();
+        Plot::new(Id::new(ID_SOURCE).with("Reference").with(name))
+            .height(height)
+            .legend(Legend::default())
+            .allow_drag(false)
+            .allow_scroll(false)
+            .y_axis_label(name)
+            .x_axis_formatter(move |mark, _| {
+                if mark.value.fract() != 0.0 || mark.value < 0.0 {
+                    return String::new();
+                }
+                labels
+                    .get(mark.value as usize)
+                    .cloned()
+                    .unwrap_or_default()
+            })
+            .label_formatter(move |name, value| {
+                if name.is_empty() {
+                    return String::new();
+                }
+                format!("{name}\n{:.precision$}", value.y)
+            })
+            .show(ui, |ui| {
+                ui.box_plot(
+                    BoxPlot::new(boxes)
+                        .name(localize!("reference"))
+                        .element_formatter(Box::new(move |element, _| {
+                            let spread = &element.spread;
+                            format!(
+                                "{}\nMin = {:.precision$}\nMedian = {:.precision$}\nMax = {:.precision$}",
+                                element.name,
+                                spread.lower_whisker,
+                                spread.median,
+                                spread.upper_whisker,
+                            )
+                        })),
+                );
+                ui.points(
+                    Points::new(inside)
+                        .name(name)
+                        .shape(MarkerShape::Circle)
+                        .filled(true)
+                        .radius(4.0),
+                );
+                ui.points(
+                    Points::new(outside)
+                        .name(localize!("out_of_range"))
+                        .shape(MarkerShape::Diamond)
+                        .filled(true)
+                        .radius(6.0)
+                        .color(color),
+                );
+                ui.points(
+                    Points::new(unreferenced)
+                        .name(localize!("no_reference"))
+                        .shape(MarkerShape::Square)
+                        .filled(true)
+                        .radius(4.0)
+                        .color(weak),
+                );
+            });
+    }
+}
+
+/// Sample value and reference statistics of a stereospecific position
+struct Position {
+    value: Vec<Option<f64>>,
+    median: Vec<Option<f64>>,
+    interquartile_range: Vec<Option<f64>>,
+    min: Vec<Option<f64>>,
+    max: Vec<Option<f64>>,
+}
+
+impl Position {
+    fn new(data_frame: &DataFrame, name: &str) -> PolarsResult<Self> {
+        let r#struct = data_frame[name].struct_()?;
+        let field = |name: &str| -> PolarsResult<Vec<Option<f64>>> {
+            Ok(r#struct.field_by_name(name)?.f64()?.to_vec())
+        };
+        Ok(Self {
+            value: field("Value")?,
+            median: field("Median")?,
+            interquartile_range: field("InterquartileRange")?,
+            min: field("Min")?,
+            max: field("Max")?,
+        })
+    }
+}
@@ -0,0 +1,96 @@
+use super::{ID_SOURCE, Settings};
+use crate::{
+    app::{
+        computers::{SelectivityComputed, SelectivityKey},
+        widgets::{FloatWidget, new_fatty_acid::FattyAcidWidget},
+    },
+    localization::localize,
+};
+use egui::{Grid, Id, ScrollArea, Ui};
+use lipid::fatty_acid::polars::DataFrameExt as _;
+use polars::prelude::*;
+
+/// Selectivity view
+pub(super) struct SelectivityView<'a> {
+    data_frame: &'a DataFrame,
+    hash: u64,
+    settings: &'a Settings,
+}
+
+impl<'a> SelectivityView<'a> {
+    pub(super) fn new(data_frame: &'a DataFrame, hash: u64, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            hash,
+            settings,
+        }
+    }
+}
+
+impl SelectivityView<'_> {
+    pub(super) fn show(&self, ui: &mut Ui) {
+        if let Err(error) = self.try_show(ui) {
+            ui.label(error.to_string());
+        }
+    }
+
+    fn try_show(&self, ui: &mut Ui) -> PolarsResult<()> {
+        if self.data_frame.is_empty() {
+            return Ok(());
+        }
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<SelectivityComputed>()
+                .get(SelectivityKey {
+                    data_frame: self.data_frame,
+                    hash: self.hash,
+                })
+        });
+        let source = data_frame["Source"].struct_()?;
+        let target = data_frame["Target"].struct_()?;
+        let columns = [
+            data_frame["Source123"].as_materialized_series().clone(),
+            data_frame["Source2"].as_materialized_series().clone(),
+            source.field_by_name("Factor")?,
+            target.field_by_name("Factor")?,
+            source.field_by_name("Relative")?,
+            target.field_by_name("Relative")?,
+        ];
+        ScrollArea::both()
+            .show(ui, |ui| -> PolarsResult<()> {
+                let id_salt = Id::new(ID_SOURCE).with("Selectivity");
+                Grid::new(id_salt)
+                    .striped(true)
+                    .show(ui, |ui| -> PolarsResult<()> {
+                        ui.heading(localize!("fatty_acid.abbreviation"));
+                        ui.heading("SN123");
+                        ui.heading("SN2");
+                        ui.heading(localize!("selectivity_factor"))
+                            .on_hover_text("SN2 / SN123");
+                        ui.heading(localize!("reference"))
+                            .on_hover_text(localize!("selectivity_factor"));
+                        ui.heading(localize!("relative"))
+                            .on_hover_text("100 * SN2 / (3 * SN123)");
+                        ui.heading(localize!("reference"))
+                            .on_hover_text(localize!("relative"));
+                        ui.end_row();
+                        for row in 0..data_frame.height() {
+                            FattyAcidWidget::new(|| data_frame.fatty_acid().get(row))
+                                .hover()
+                                .ui(ui)?;
+                            for column in &columns {
+                                FloatWidget::new(|| Ok(column.f64()?.get(row)))
+                                    .precision(Some(self.settings.precision))
+                                    .hover()
+                                    .ui(ui);
+                            }
+                            ui.end_row();
+                        }
+                        Ok(())
+                    })
+                    .inner
+            })
+            .inner
+    }
+}
@@ -0,0 +1,168 @@
+use super::{ID_SOURCE, Settings, state::State};
+use crate::{
+    app::{
+        computers::{SensitivityComputed, SensitivityKey},
+        widgets::{DeltaWidget, FloatWidget},
+    },
+    localization::localize,
+};
+use egui::{DragValue, Grid, Id, ScrollArea, Ui, Widget};
+use egui_plot::{Bar, BarChart, Legend, Plot};
+use lipid::fatty_acid::{
+    display::{COMMON, DisplayWithOptions},
+    polars::DataFrameExt as _,
+};
+use polars::prelude::*;
+
+const TOP: usize = 16;
+
+/// Sensitivity view
+pub(super) struct SensitivityView<'a> {
+    data_frame: &'a DataFrame,
+    hash: u64,
+    settings: &'a Settings,
+    state: &'a mut State,
+}
+
+impl<'a> SensitivityView<'a> {
+    pub(super) fn new(
+        data_frame: &'a DataFrame,
+        hash: u64,
+        settings: &'a Settings,
+        state: &'a mut State,
+    ) -> Self {
+        Self {
+            data_frame,
+            hash,
+            settings,
+            state,
+        }
+    }
+}
+
+impl SensitivityView<'_> {
+    pub(super) fn show(&mut self, ui: &mut Ui) {
+        if let Err(error) = self.try_show(ui) {
+            ui.label(error.to_string());
+        }
+    }
+
+    fn try_show(&mut self, ui: &mut Ui) -> PolarsResult<()> {
+        if self.data_frame.is_empty() {
+            return Ok(());
+        }
+        ui.horizontal(|ui| {
+            ui.label(localize!("perturbation"))
+                .on_hover_text(localize!("perturbation.description"));
+            DragValue::new(&mut self.state.perturbation)
+                .range(0.1..=100.0)
+                .speed(0.1)
+                .suffix("%")
+                .ui(ui);
+        });
+        ui.separator();
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<SensitivityComputed>()
+                .get(SensitivityKey {
+                    data_frame: self.data_frame,
+                    hash: self.hash,
+                    settings: self.settings,
+                    perturbation: self.state.perturbation,
+                })
+        });
+        let fatty_acids = self.data_frame.fatty_acid();
+        let indices = data_frame["Index"].u32()?;
+        let positions = data_frame["Position"].str()?;
+        let mut labels = Vec::with_capacity(data_frame.height());
+        for row in 0..data_frame.height() {
+            let fatty_acid = match indices.get(row) {
+                Some(index) => fatty_acids
+                    .get(index as _)?
+                    .map(|fatty_acid| format!("{:#}", (&fatty_acid).display(COMMON)))
+                    .unwrap_or_default(),
+                None => String::new(),
+            };
+            let position = match positions.get(row) {
+                Some("StereospecificNumber123") => "SN123",
+                Some("StereospecificNumber2") => "SN2",
+                _ => "",
+            };
+            labels.push(format!("{fatty_acid} {position}"));
+        }
+        let values = data_frame["Value"].f64()?;
+        let lower = data_frame["Lower"].f64()?;
+        let upper = data_frame["Upper"].f64()?;
+        self.plot(ui, &labels, lower, upper);
+        let precision = self.settings.precision;
+        ScrollArea::both().show(ui, |ui| {
+            Grid::new(Id::new(ID_SOURCE).with("Sensitivity"))
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.heading("#");
+                    ui.heading(localize!("fatty_acid.abbreviation"));
+                    ui.heading(localize!("value"));
+                    ui.heading(format!("-{}%", self.state.perturbation))
+                        .on_hover_text("ΔG");
+                    ui.heading(format!("+{}%", self.state.perturbation))
+                        .on_hover_text("ΔG");
+                    ui.end_row();
+                    for row in 0..data_frame.height() {
+                        ui.label((row + 1).to_string());
+                        ui.label(&labels[row]);
+                        FloatWidget::new(|| Ok(values.get(row)))
+                            .precision(Some(precision))
+                            .hover()
+                            .ui(ui);
+                        DeltaWidget::new(lower.get(row)).precision(precision).ui(ui);
+                        DeltaWidget::new(upper.get(row)).precision(precision).ui(ui);
+                        ui.end_row();
+                    }
+                });
+        });
+        Ok(())
+    }
+
+    /// Tornado chart of the most influential inputs
+    fn plot(&self, ui: &mut Ui, labels: &[String], lower: &Float64Chunked, upper: &Float64Chunked) {
+        let count = labels.len().min(TOP);
+        let bars = |values: &Float64Chunked| {
+            (0..count)
+                .map(|row| {
+                    Bar::new((count - row) as f64, values.get(row).unwrap_or_default())
+                        .name(&labels[row])
+                })
+                .collect::<Vec<_>>()
+        };
+        let perturbation = self.state.perturbation;
+        let mut names = vec![String::new(); count + 1];
+        for (row, label) in labels.iter().take(count).enumerate() {
+            names[count - row] = label.clone();
+        }
+        Plot::new(Id::new(ID_SOURCE).with("Tornado"))
+            .height(ui.available_height() / 2.0)
+            .legend(Legend::default())
+            .allow_drag(false)
+            .allow_scroll(false)
+            .x_axis_label("ΔG")
+            .y_axis_formatter(move |mark, _| {
+                if mark.value.fract() != 0.0 || mark.value < 0.0 {
+                    return String::new();
+                }
+                names.get(mark.value as usize).cloned().unwrap_or_default()
+            })
+            .show(ui, |ui| {
+                ui.bar_chart(
+                    BarChart::new(bars(lower))
+                        .horizontal()
+                        .name(format!("-{perturbation}%")),
+                );
+                ui.bar_chart(
+                    BarChart::new(bars(upper))
+                        .horizontal()
+                        .name(format!("+{perturbation}%")),
+                );
+            });
+    }
+}
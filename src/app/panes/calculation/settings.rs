@@ -5,7 +5,10 @@ use serde::{Deserialize, Serialize};
 use super::ID_SOURCE;
 
 /// Settings
+///
+/// Missing fields of older saved states are the defaults.
 #[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[serde(default)]
 pub(crate) struct Settings {
     #[serde(skip)]
     pub(crate) resizable: bool,
@@ -19,6 +22,8 @@ pub(crate) struct Settings {
 
     pub(crate) relative: bool,
     pub(crate) properties: bool,
+
+    pub(crate) composition: Composition,
 }
 
 impl Settings {
@@ -33,6 +38,7 @@ impl Settings {
             truncate: false,
             relative: true,
             properties: true,
+            composition: Composition::Mass,
         }
     }
 
@@ -65,6 +71,28 @@ impl Settings {
             ui.label(localize!("relative"));
             ui.checkbox(&mut self.relative, "")
                 .on_hover_text(localize!("relative_description"));
+            ui.end_row();
+
+            ui.separator();
+            ui.separator();
+            ui.end_row();
+
+            // Composition
+            ui.label(localize!("composition"));
+            ui.horizontal(|ui| {
+                ui.selectable_value(
+                    &mut self.composition,
+                    Composition::Mass,
+                    localize!("mass_composition.abbreviation"),
+                )
+                .on_hover_text(localize!("mass_composition"));
+                ui.selectable_value(
+                    &mut self.composition,
+                    Composition::PositionalMass,
+                    localize!("positional_mass_composition.abbreviation"),
+                )
+                .on_hover_text(localize!("positional_mass_composition"));
+            });
         });
     }
 }
@@ -74,3 +102,11 @@ impl Default for Settings {
         Self::new()
     }
 }
+
+/// Composition
+#[derive(Clone, Copy, Debug, Default, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Composition {
+    #[default]
+    Mass,
+    PositionalMass,
+}
@@ -1,11 +1,31 @@
 use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
 
-#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+/// State
+///
+/// Missing fields of older saved states are the defaults.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(default)]
 pub(crate) struct State {
     pub(crate) add_table_row: bool,
     pub(crate) delete_table_row: Option<usize>,
     pub(crate) open_settings_window: bool,
     pub(crate) reset_table_state: bool,
+    pub(crate) view: View,
+    pub(crate) goal: Goal,
+    /// Sensitivity perturbation, %
+    pub(crate) perturbation: f64,
+    /// Explained row, the totals if none
+    pub(crate) explain: Option<usize>,
+    /// Selected table cells
+    #[serde(skip)]
+    pub(crate) selection: Option<Selection>,
+    /// Table row to scroll to once
+    #[serde(skip)]
+    pub(crate) scroll_to_row: Option<usize>,
+    /// Content hash of the data, reset on edit
+    #[serde(skip)]
+    pub(crate) hash: Option<u64>,
 }
 
 impl State {
@@ -15,6 +35,82 @@ impl State {
             delete_table_row: None,
             open_settings_window: false,
             reset_table_state: false,
+            view: View::Table,
+            goal: Goal::Zero,
+            perturbation: 5.0,
+            explain: None,
+            selection: None,
+            scroll_to_row: None,
+            hash: None,
+        }
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Selected range of table cells, the footer row is the source height
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Selection {
+    /// Cell (row, column) where the selection started
+    pub(crate) anchor: (usize, usize),
+    /// Cell (row, column) where the selection ends
+    pub(crate) cursor: (usize, usize),
+}
+
+impl Selection {
+    pub(crate) const fn new(row: usize, column: usize) -> Self {
+        Self {
+            anchor: (row, column),
+            cursor: (row, column),
+        }
+    }
+
+    pub(crate) fn rows(&self) -> RangeInclusive<usize> {
+        self.anchor.0.min(self.cursor.0)..=self.anchor.0.max(self.cursor.0)
+    }
+
+    pub(crate) fn columns(&self) -> RangeInclusive<usize> {
+        self.anchor.1.min(self.cursor.1)..=self.anchor.1.max(self.cursor.1)
+    }
+
+    pub(crate) fn contains(&self, row: usize, column: usize) -> bool {
+        self.rows().contains(&row) && self.columns().contains(&column)
+    }
+}
+
+/// View
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub(crate) enum View {
+    #[default]
+    Table,
+    Composition,
+    Selectivity,
+    Contribution,
+    Reference,
+    GoalSeek,
+    Sensitivity,
+    Explain,
+}
+
+/// Goal
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub(crate) enum Goal {
+    /// Zero deviation from the reference
+    #[default]
+    Zero,
+    /// Target score
+    Score(f64),
+}
+
+impl Goal {
+    pub(crate) const fn target(&self) -> f64 {
+        match *self {
+            Self::Zero => 100.0,
+            Self::Score(score) => score,
         }
     }
 }
@@ -1,10 +1,21 @@
-use super::{ID_SOURCE, Settings, State};
-use crate::app::{
-    MARGIN,
-    computers::{CalculationComputed, CalculationKey},
-    widgets::{FloatWidget, new_fatty_acid::FattyAcidWidget},
+use super::{
+    ID_SOURCE, Settings, State,
+    export::{copy, flatten},
+    problems::text,
+    state::Selection,
 };
-use egui::{Frame, Id, Margin, Response, TextStyle, TextWrapMode, Ui};
+use crate::{
+    app::{
+        MARGIN,
+        computers::{
+            CalculationComputed, CalculationKey, Problem, ProblemField, ValidationComputed,
+            ValidationKey,
+        },
+        widgets::{FloatWidget, new_fatty_acid::FattyAcidWidget},
+    },
+    utils::fatty_acid_series,
+};
+use egui::{Event, Frame, Id, Key, Margin, Response, TextStyle, TextWrapMode, Ui};
 use egui_phosphor::regular::{MINUS, PLUS};
 use egui_table::{
     AutoSizeMode, CellInfo, Column, HeaderCellInfo, HeaderRow, Table, TableDelegate, TableState,
@@ -13,9 +24,10 @@ use lipid::fatty_acid::{
     FattyAcid,
     polars::{DataFrameExt as _, SeriesExt as _},
 };
-use polars::{chunked_array::builder::AnonymousOwnedListBuilder, prelude::*};
+use polars::prelude::*;
 use re_ui::UiExt as _;
 use std::ops::Range;
+use tracing::error;
 
 const ID: Range<usize> = 0..2;
 const EXPERIMENTAL: Range<usize> = ID.end..ID.end + 2;
@@ -41,7 +53,9 @@ const E: &str = "$E = 50 * (C * D / ∑ D)$";
 /// Table view
 pub(super) struct TableView<'a> {
     source: &'a mut DataFrame,
+    hash: u64,
     target: DataFrame,
+    problems: Vec<Problem>,
     settings: &'a Settings,
     state: &'a mut State,
 }
@@ -49,12 +63,15 @@ pub(super) struct TableView<'a> {
 impl<'a> TableView<'a> {
     pub(super) fn new(
         data_frame: &'a mut DataFrame,
+        hash: u64,
         settings: &'a Settings,
         state: &'a mut State,
     ) -> Self {
         Self {
             source: data_frame,
+            hash,
             target: DataFrame::empty(),
+            problems: Vec::new(),
             settings,
             state,
         }
@@ -69,9 +86,19 @@ impl TableView<'_> {
                 .cache::<CalculationComputed>()
                 .get(CalculationKey {
                     data_frame: self.source,
+                    hash: self.hash,
                     settings: self.settings,
                 })
         });
+        self.problems = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<ValidationComputed>()
+                .get(ValidationKey {
+                    data_frame: self.source,
+                    hash: self.hash,
+                })
+        });
         let id_salt = Id::new(ID_SOURCE).with("Table");
         if self.state.reset_table_state {
             let id = TableState::id(ui, Id::new(id_salt));
@@ -81,7 +108,7 @@ impl TableView<'_> {
         let height = ui.text_style_height(&TextStyle::Heading);
         let num_rows = self.source.height() as u64 + 1;
         let num_columns = LEN;
-        Table::new()
+        let mut table = Table::new()
             .id_salt(id_salt)
             .num_rows(num_rows)
             .columns(vec![
@@ -100,18 +127,54 @@ impl TableView<'_> {
                 },
                 HeaderRow::new(height),
             ])
-            .auto_size_mode(AutoSizeMode::OnParentResize)
-            .show(ui, self);
+            .auto_size_mode(AutoSizeMode::OnParentResize);
+        if let Some(row) = self.state.scroll_to_row.take() {
+            table = table.scroll_to_row(row as _, None);
+        }
+        table.show(ui, self);
+        if let Err(error) = self.selection(ui) {
+            error!(%error);
+        }
         if self.state.add_table_row {
             self.add_row().unwrap();
             self.state.add_table_row = false;
+            self.state.hash = None;
         }
         if let Some(index) = self.state.delete_table_row {
             self.delete_row(index).unwrap();
             self.state.delete_table_row = None;
+            self.state.hash = None;
         }
     }
 
+    /// Copies the selected cells on `Ctrl+C`, clears the selection on `Escape`
+    fn selection(&mut self, ui: &Ui) -> PolarsResult<()> {
+        let Some(selection) = self.state.selection else {
+            return Ok(());
+        };
+        if !ui.ui_contains_pointer() || ui.memory(|memory| memory.focused().is_some()) {
+            return Ok(());
+        }
+        if ui.input(|input| input.key_pressed(Key::Escape)) {
+            self.state.selection = None;
+        } else if ui.input(|input| {
+            input
+                .events
+                .iter()
+                .any(|event| matches!(event, Event::Copy))
+        }) {
+            let data_frame = flatten(self.source, &self.target)?;
+            let text = copy(
+                &data_frame,
+                self.source.height(),
+                Some(selection),
+                self.settings.precision,
+            )?;
+            ui.ctx().copy_text(text);
+        }
+        Ok(())
+    }
+
     fn header_cell_content_ui(&mut self, ui: &mut Ui, row: usize, column: Range<usize>) {
         if self.settings.truncate {
             ui.style_mut().wrap_mode = Some(TextWrapMode::Truncate);
@@ -210,6 +273,7 @@ impl TableView<'_> {
                 if let Some(value) = inner_response.inner {
                     self.source
                         .try_apply("FattyAcid", change_fatty_acid(row, &value))?;
+                    self.state.hash = None;
                 }
             }
             (row, &experimental::SN123) => {
@@ -494,6 +558,7 @@ impl TableView<'_> {
         if let Some(value) = inner_response.inner {
             self.source
                 .try_apply(column, change_experimental(row, value))?;
+            self.state.hash = None;
         }
         Ok(inner_response.response)
     }
@@ -507,7 +572,6 @@ impl TableView<'_> {
     }
 
     fn add_row(&mut self) -> PolarsResult<()> {
-        println!("self.source: {}", self.source);
         *self.source = self.source.vstack(&df! {
             "FattyAcid" => df! {
                 "Carbons" => &[0u8],
@@ -572,16 +636,71 @@ impl TableDelegate for TableView<'_> {
     }
 
     fn cell_ui(&mut self, ui: &mut Ui, cell: &CellInfo) {
+        let (row, column) = (cell.row_nr as usize, cell.col_nr);
         if cell.row_nr % 2 == 0 {
             ui.painter()
                 .rect_filled(ui.max_rect(), 0.0, ui.visuals().faint_bg_color);
         }
-        Frame::none()
+        // Selection: click, shift click or drag
+        if ui.rect_contains_pointer(ui.max_rect()) {
+            let (pressed, down, shift) = ui.input(|input| {
+                (
+                    input.pointer.primary_pressed(),
+                    input.pointer.primary_down(),
+                    input.modifiers.shift,
+                )
+            });
+            match &mut self.state.selection {
+                Some(selection) if (pressed && shift) || (!pressed && down) => {
+                    selection.cursor = (row, column);
+                }
+                selection if pressed => *selection = Some(Selection::new(row, column)),
+                _ => {}
+            }
+        }
+        if self
+            .state
+            .selection
+            .is_some_and(|selection| selection.contains(row, column))
+        {
+            ui.painter().rect_filled(
+                ui.max_rect(),
+                0.0,
+                ui.visuals().selection.bg_fill.gamma_multiply(0.5),
+            );
+        }
+        // Problems
+        let problems = self
+            .problems
+            .iter()
+            .filter(|problem| problem.row == row && self::column(problem.field) == column)
+            .map(|problem| text(&problem.kind, self.settings.precision))
+            .collect::<Vec<_>>();
+        if !problems.is_empty() {
+            ui.painter().rect_filled(
+                ui.max_rect(),
+                0.0,
+                ui.visuals().error_fg_color.gamma_multiply(0.25),
+            );
+        }
+        let response = Frame::none()
             .inner_margin(Margin::symmetric(MARGIN.x, MARGIN.y))
             .show(ui, |ui| {
-                self.cell_content_ui(ui, cell.row_nr as _, cell.col_nr..cell.col_nr + 1)
-                    .unwrap()
-            });
+                self.cell_content_ui(ui, row, column..column + 1).unwrap()
+            })
+            .response;
+        if !problems.is_empty() {
+            response.on_hover_text(problems.join("\n"));
+        }
+    }
+}
+
+/// Table column of the checked field
+pub(super) const fn column(field: ProblemField) -> usize {
+    match field {
+        ProblemField::FattyAcid => id::FA.start,
+        ProblemField::StereospecificNumber123 => experimental::SN123.start,
+        ProblemField::StereospecificNumber2 => experimental::SN2.start,
     }
 }
 
@@ -590,89 +709,16 @@ fn change_fatty_acid(
     new: &FattyAcid,
 ) -> impl FnMut(&Series) -> PolarsResult<Series> + '_ {
     move |series| {
-        let fatty_acid_series = series.fatty_acid();
-        let mut carbons = PrimitiveChunkedBuilder::<UInt8Type>::new(
-            fatty_acid_series.carbons.name().clone(),
-            fatty_acid_series.len(),
-        );
-        let mut unsaturated = AnonymousOwnedListBuilder::new(
-            fatty_acid_series.unsaturated.name().clone(),
-            fatty_acid_series.len(),
-            fatty_acid_series.unsaturated.dtype().inner_dtype().cloned(),
-        );
-        for index in 0..fatty_acid_series.len() {
-            let mut fatty_acid = fatty_acid_series.get(index)?;
-            if index == row {
-                fatty_acid = Some(new.clone());
-            }
-            let fatty_acid = fatty_acid.as_ref();
-            // Carbons
-            carbons.append_option(fatty_acid.map(|fatty_acid| fatty_acid.carbons));
-            // Unsaturated
-            if let Some(fatty_acid) = fatty_acid {
-                // let mut fields = Vec::with_capacity(fatty_acid.unsaturated.len());
-                // if let Some(unsaturated_series) = fatty_acid_series.unsaturated(index)? {
-                //     fields.push(unsaturated_series.index);
-                //     fields.push(unsaturated_series.isomerism);
-                //     fields.push(unsaturated_series.unsaturation);
-                // }
-                // unsaturated.append_series(
-                //     &StructChunked::from_series(
-                //         PlSmallStr::EMPTY,
-                //         fatty_acid.unsaturated.len(),
-                //         fields.iter(),
-                //     )?
-                //     .into_series(),
-                // )?;
-                let mut index = PrimitiveChunkedBuilder::<UInt8Type>::new(
-                    "Index".into(),
-                    fatty_acid.unsaturated.len(),
-                );
-                let mut isomerism = PrimitiveChunkedBuilder::<Int8Type>::new(
-                    "Isomerism".into(),
-                    fatty_acid.unsaturated.len(),
-                );
-                let mut unsaturation = PrimitiveChunkedBuilder::<UInt8Type>::new(
-                    "Unsaturation".into(),
-                    fatty_acid.unsaturated.len(),
-                );
-                for unsaturated in &fatty_acid.unsaturated {
-                    index.append_option(unsaturated.index);
-                    isomerism.append_option(unsaturated.isomerism.map(|isomerism| isomerism as _));
-                    unsaturation.append_option(
-                        unsaturated
-                            .unsaturation
-                            .map(|unsaturation| unsaturation as _),
-                    );
-                }
-                unsaturated.append_series(
-                    &StructChunked::from_series(
-                        PlSmallStr::EMPTY,
-                        fatty_acid.unsaturated.len(),
-                        [
-                            index.finish().into_series(),
-                            isomerism.finish().into_series(),
-                            unsaturation.finish().into_series(),
-                        ]
-                        .iter(),
-                    )?
-                    .into_series(),
-                )?;
+        let fatty_acid = series.fatty_acid();
+        let mut fatty_acids = Vec::with_capacity(fatty_acid.len());
+        for index in 0..fatty_acid.len() {
+            fatty_acids.push(if index == row {
+                Some(new.clone())
             } else {
-                println!("HERE1");
-                unsaturated.append_opt_series(None)?;
-            }
+                fatty_acid.get(index)?
+            });
         }
-        Ok(StructChunked::from_series(
-            series.name().clone(),
-            fatty_acid_series.len(),
-            [
-                carbons.finish().into_series(),
-                unsaturated.finish().into_series(),
-            ]
-            .iter(),
-        )?
-        .into_series())
+        fatty_acid_series(series.name().clone(), &fatty_acids)
     }
 }
 
@@ -0,0 +1,91 @@
+use self::{settings::Settings, state::State, table::TableView};
+use super::Sample;
+use crate::localization::localize;
+use egui::{CursorIcon, Response, RichText, ScrollArea, Ui, Window, menu::bar};
+use egui_phosphor::regular::{ARROWS_CLOCKWISE, ARROWS_HORIZONTAL, GEAR, GIT_DIFF};
+use serde::{Deserialize, Serialize};
+
+const ID_SOURCE: &str = "Comparison";
+
+/// Comparison pane
+#[derive(Default, Deserialize, Serialize)]
+pub(crate) struct Pane {
+    pub(crate) settings: Settings,
+    state: State,
+}
+
+impl Pane {
+    pub(crate) const fn new() -> Self {
+        Self {
+            settings: Settings::new(),
+            state: State::new(),
+        }
+    }
+
+    pub(crate) const fn icon() -> &'static str {
+        GIT_DIFF
+    }
+
+    pub(crate) fn title(&self) -> String {
+        localize!("comparison")
+    }
+
+    pub(crate) fn header(&mut self, ui: &mut Ui) -> Response {
+        bar(ui, |ui| {
+            ScrollArea::horizontal()
+                .show(ui, |ui| {
+                    ui.visuals_mut().button_frame = false;
+                    self.header_content(ui)
+                })
+                .inner
+        })
+        .inner
+    }
+
+    fn header_content(&mut self, ui: &mut Ui) -> Response {
+        let mut response = ui
+            .heading(Self::icon())
+            .on_hover_text(localize!("comparison"));
+        response |= ui.heading(self.title());
+        response = response.on_hover_cursor(CursorIcon::Grab);
+        ui.separator();
+        // Reset
+        if ui
+            .button(RichText::new(ARROWS_CLOCKWISE).heading())
+            .clicked()
+        {
+            self.state.reset_table_state = true;
+        }
+        // Resize
+        ui.toggle_value(
+            &mut self.settings.resizable,
+            RichText::new(ARROWS_HORIZONTAL).heading(),
+        )
+        .on_hover_text(localize!("resize"));
+        ui.separator();
+        // Settings
+        ui.toggle_value(
+            &mut self.state.open_settings_window,
+            RichText::new(GEAR).heading(),
+        )
+        .on_hover_text(localize!("settings"));
+        response
+    }
+
+    pub(crate) fn body(&mut self, ui: &mut Ui, samples: &[Sample]) {
+        self.windows(ui, samples);
+        TableView::new(samples, &self.settings, &mut self.state).show(ui);
+    }
+
+    pub(crate) fn windows(&mut self, ui: &mut Ui, samples: &[Sample]) {
+        Window::new(format!("{GEAR} Settings"))
+            .id(ui.auto_id_with(ID_SOURCE))
+            .open(&mut self.state.open_settings_window)
+            .show(ui.ctx(), |ui| self.settings.show(ui, samples));
+    }
+}
+
+pub(crate) mod settings;
+
+mod state;
+mod table;
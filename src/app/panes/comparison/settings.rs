@@ -0,0 +1,52 @@
+use super::ID_SOURCE;
+use crate::{
+    app::{MAX_PRECISION, panes::Sample, widgets::SamplesWidget},
+    localization::localize,
+};
+use egui::{Grid, Id, Slider, Ui, Widget};
+use serde::{Deserialize, Serialize};
+
+/// Settings
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) struct Settings {
+    #[serde(skip)]
+    pub(crate) resizable: bool,
+    pub(crate) precision: usize,
+    pub(crate) sticky: usize,
+    pub(crate) truncate: bool,
+
+    /// Compared samples, the first one is the baseline
+    pub(crate) samples: Vec<String>,
+}
+
+impl Settings {
+    pub(crate) const fn new() -> Self {
+        Self {
+            resizable: false,
+            precision: 2,
+            sticky: 2,
+            truncate: false,
+            samples: Vec::new(),
+        }
+    }
+
+    pub(crate) fn show(&mut self, ui: &mut Ui, samples: &[Sample]) {
+        let id_salt = Id::new(ID_SOURCE).with("Settings");
+        Grid::new(id_salt).show(ui, |ui| {
+            // Precision
+            ui.label(localize!("precision"));
+            Slider::new(&mut self.precision, 0..=MAX_PRECISION).ui(ui);
+            ui.end_row();
+        });
+        ui.separator();
+        // Samples
+        ui.label(localize!("baseline"));
+        SamplesWidget::new(&mut self.samples, samples).ui(ui);
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,347 @@
+use super::{ID_SOURCE, Settings, State};
+use crate::{
+    app::{
+        MARGIN,
+        panes::{Sample, sample::scores},
+        widgets::{DeltaWidget, FloatWidget},
+    },
+    localization::localize,
+};
+use egui::{Frame, Grid, Id, Margin, RichText, TextStyle, TextWrapMode, Ui};
+use egui_table::{
+    AutoSizeMode, CellInfo, Column, HeaderCellInfo, HeaderRow, Table, TableDelegate, TableState,
+};
+use polars::prelude::*;
+use std::ops::Range;
+
+const ID: Range<usize> = 0..2;
+const BASELINE: &[&str] = &["SN123", "SN2"];
+const COMPARED: &[&str] = &["SN123", "SN2", "ΔSN123", "ΔSN2", "δSN123", "δSN2"];
+
+/// Comparison table view
+pub(super) struct TableView<'a> {
+    samples: &'a [Sample],
+    settings: &'a Settings,
+    state: &'a mut State,
+    comparison: Comparison,
+}
+
+impl<'a> TableView<'a> {
+    pub(super) fn new(samples: &'a [Sample], settings: &'a Settings, state: &'a mut State) -> Self {
+        Self {
+            samples,
+            settings,
+            state,
+            comparison: Comparison::default(),
+        }
+    }
+}
+
+impl TableView<'_> {
+    pub(super) fn show(&mut self, ui: &mut Ui) {
+        match self.compare(ui) {
+            Ok(comparison) => self.comparison = comparison,
+            Err(error) => {
+                ui.label(error.to_string());
+                return;
+            }
+        }
+        if self.comparison.titles.len() < 2 {
+            ui.label(localize!("comparison_description"));
+            return;
+        }
+        self.summary(ui);
+        let id_salt = Id::new(ID_SOURCE).with("Table");
+        if self.state.reset_table_state {
+            let id = TableState::id(ui, Id::new(id_salt));
+            TableState::reset(ui.ctx(), id);
+            self.state.reset_table_state = false;
+        }
+        let height = ui.text_style_height(&TextStyle::Heading);
+        let num_rows = self.comparison.labels.len() as u64 + 1;
+        let num_samples = self.comparison.titles.len();
+        let num_columns = self.sample(num_samples - 1).end;
+        let mut groups = vec![ID];
+        for index in 0..num_samples {
+            groups.push(self.sample(index));
+        }
+        Table::new()
+            .id_salt(id_salt)
+            .num_rows(num_rows)
+            .columns(vec![
+                Column::default().resizable(self.settings.resizable);
+                num_columns
+            ])
+            .num_sticky_cols(self.settings.sticky)
+            .headers([HeaderRow { height, groups }, HeaderRow::new(height)])
+            .auto_size_mode(AutoSizeMode::OnParentResize)
+            .show(ui, self);
+    }
+
+    /// Columns of the sample group
+    fn sample(&self, index: usize) -> Range<usize> {
+        if index == 0 {
+            ID.end..ID.end + BASELINE.len()
+        } else {
+            let start = ID.end + BASELINE.len() + (index - 1) * COMPARED.len();
+            start..start + COMPARED.len()
+        }
+    }
+
+    /// Sample index and column offset inside its group
+    fn locate(&self, column: usize) -> (usize, usize) {
+        let column = column - ID.end;
+        if column < BASELINE.len() {
+            (0, column)
+        } else {
+            let column = column - BASELINE.len();
+            (1 + column / COMPARED.len(), column % COMPARED.len())
+        }
+    }
+
+    fn compare(&self, ui: &Ui) -> PolarsResult<Comparison> {
+        let selected = self
+            .settings
+            .samples
+            .iter()
+            .filter_map(|title| Sample::find(self.samples, title))
+            .collect::<Vec<_>>();
+        let mut comparison = Comparison::default();
+        let mut columns = Vec::with_capacity(selected.len());
+        for sample in &selected {
+            comparison.titles.push(sample.title.clone());
+            if sample.frame.data.is_empty() {
+                columns.push((Vec::new(), Vec::new(), Vec::new()));
+                comparison.scores.push([None; 3]);
+                continue;
+            }
+            let data_frame = &sample.frame.data;
+            columns.push((
+                sample.labels()?,
+                data_frame
+                    .column("StereospecificNumber123")?
+                    .f64()?
+                    .to_vec(),
+                data_frame.column("StereospecificNumber2")?.f64()?.to_vec(),
+            ));
+            comparison.scores.push(scores(&sample.target(ui))?);
+        }
+        // Union of fatty acids in order of appearance
+        let mut labels = Vec::<String>::new();
+        for (sample_labels, ..) in &columns {
+            for label in sample_labels.iter().flatten() {
+                if !labels.contains(label) {
+                    labels.push(label.clone());
+                }
+            }
+        }
+        for label in labels {
+            let positions = columns
+                .iter()
+                .map(|(sample_labels, ..)| {
+                    sample_labels
+                        .iter()
+                        .position(|other| other.as_ref() == Some(&label))
+                })
+                .collect::<Vec<_>>();
+            if positions.iter().all(Option::is_some) {
+                comparison.values.push(
+                    columns
+                        .iter()
+                        .zip(&positions)
+                        .map(|((_, sn123, sn2), position)| {
+                            let index = position.unwrap();
+                            [sn123[index], sn2[index]]
+                        })
+                        .collect(),
+                );
+                comparison.labels.push(label);
+            } else {
+                comparison
+                    .unmatched
+                    .push((label, positions.iter().map(Option::is_some).collect()));
+            }
+        }
+        Ok(comparison)
+    }
+
+    fn summary(&self, ui: &mut Ui) {
+        let precision = self.settings.precision;
+        let id = Id::new(ID_SOURCE).with("Summary");
+        ui.collapsing(RichText::new(localize!("score")).heading(), |ui| {
+            Grid::new(id.with("Scores")).striped(true).show(ui, |ui| {
+                ui.label(localize!("sample"));
+                ui.label("G1");
+                ui.label("G2");
+                ui.label("G");
+                ui.label("ΔG");
+                ui.end_row();
+                let baseline = self.comparison.scores[0][2];
+                for (title, scores) in self.comparison.titles.iter().zip(&self.comparison.scores) {
+                    ui.label(title);
+                    for score in scores {
+                        FloatWidget::new(|| Ok(*score))
+                            .precision(Some(precision))
+                            .hover()
+                            .ui(ui);
+                    }
+                    delta(ui, scores[2], baseline, precision, false);
+                    ui.end_row();
+                }
+            });
+            if !self.comparison.unmatched.is_empty() {
+                ui.separator();
+                ui.label(localize!("unmatched"));
+                Grid::new(id.with("Unmatched"))
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.label(localize!("fatty_acid.abbreviation"));
+                        for title in &self.comparison.titles {
+                            ui.label(title);
+                        }
+                        ui.end_row();
+                        for (label, present) in &self.comparison.unmatched {
+                            ui.label(label);
+                            for &present in present {
+                                ui.label(if present { "✔" } else { "✖" });
+                            }
+                            ui.end_row();
+                        }
+                    });
+            }
+        });
+    }
+
+    fn header_cell_content_ui(&mut self, ui: &mut Ui, row: usize, column: Range<usize>) {
+        if self.settings.truncate {
+            ui.style_mut().wrap_mode = Some(TextWrapMode::Truncate);
+        }
+        match row {
+            // Top
+            0 if column == ID => {
+                ui.heading("ID");
+            }
+            0 => {
+                let (index, _) = self.locate(column.start);
+                let title = &self.comparison.titles[index];
+                if index == 0 {
+                    ui.heading(title).on_hover_text(localize!("baseline"));
+                } else {
+                    ui.heading(title);
+                }
+            }
+            // Bottom
+            _ if column.start == ID.start => {
+                ui.heading("Index");
+            }
+            _ if column.start < ID.end => {
+                ui.heading("FA");
+            }
+            _ => {
+                let (index, offset) = self.locate(column.start);
+                if index == 0 {
+                    ui.heading(BASELINE[offset]);
+                } else {
+                    let response = ui.heading(COMPARED[offset]);
+                    match offset {
+                        2 | 3 => response.on_hover_text("B - A"),
+                        4 | 5 => response.on_hover_text("100 * (B - A) / A"),
+                        _ => response,
+                    };
+                }
+            }
+        }
+    }
+
+    fn cell_content_ui(&mut self, ui: &mut Ui, row: usize, column: usize) {
+        let precision = self.settings.precision;
+        let footer = row == self.comparison.labels.len();
+        if column == ID.start {
+            if !footer {
+                ui.label((row + 1).to_string());
+            }
+            return;
+        }
+        if column < ID.end {
+            if footer {
+                ui.label(localize!("score"));
+            } else {
+                ui.label(&self.comparison.labels[row]);
+            }
+            return;
+        }
+        let (index, offset) = self.locate(column);
+        let position = offset % 2;
+        let (value, baseline) = if footer {
+            (
+                self.comparison.scores[index][position],
+                self.comparison.scores[0][position],
+            )
+        } else {
+            (
+                self.comparison.values[row][index][position],
+                self.comparison.values[row][0][position],
+            )
+        };
+        match offset {
+            0 | 1 => {
+                let response = FloatWidget::new(|| Ok(value))
+                    .precision(Some(precision))
+                    .hover()
+                    .ui(ui)
+                    .response;
+                if footer {
+                    response.on_hover_text(["G1", "G2"][position]);
+                }
+            }
+            2 | 3 => delta(ui, value, baseline, precision, false),
+            _ => delta(ui, value, baseline, precision, true),
+        }
+    }
+}
+
+impl TableDelegate for TableView<'_> {
+    fn header_cell_ui(&mut self, ui: &mut Ui, cell: &HeaderCellInfo) {
+        Frame::none()
+            .inner_margin(Margin::symmetric(MARGIN.x, MARGIN.y))
+            .show(ui, |ui| {
+                self.header_cell_content_ui(ui, cell.row_nr, cell.col_range.clone())
+            });
+    }
+
+    fn cell_ui(&mut self, ui: &mut Ui, cell: &CellInfo) {
+        if cell.row_nr % 2 == 0 {
+            ui.painter()
+                .rect_filled(ui.max_rect(), 0.0, ui.visuals().faint_bg_color);
+        }
+        Frame::none()
+            .inner_margin(Margin::symmetric(MARGIN.x, MARGIN.y))
+            .show(ui, |ui| {
+                self.cell_content_ui(ui, cell.row_nr as _, cell.col_nr)
+            });
+    }
+}
+
+/// Colored absolute or relative difference
+fn delta(ui: &mut Ui, value: Option<f64>, baseline: Option<f64>, precision: usize, relative: bool) {
+    let delta = value.zip(baseline).and_then(|(value, baseline)| {
+        if !relative {
+            Some(value - baseline)
+        } else if baseline != 0.0 {
+            Some(100.0 * (value - baseline) / baseline)
+        } else {
+            None
+        }
+    });
+    DeltaWidget::new(delta).precision(precision).ui(ui);
+}
+
+/// Comparison
+#[derive(Default)]
+struct Comparison {
+    titles: Vec<String>,
+    labels: Vec<String>,
+    values: Vec<Vec<[Option<f64>; 2]>>,
+    scores: Vec<[Option<f64>; 3]>,
+    unmatched: Vec<(String, Vec<bool>)>,
+}
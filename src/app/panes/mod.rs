@@ -1,4 +1,91 @@
-pub(crate) use self::calculation::Pane;
+pub(crate) use self::sample::Sample;
+
+use egui::{Response, Ui};
+use metadata::MetaDataFrame;
+use serde::{Deserialize, Serialize};
+
+/// Pane
+#[derive(Deserialize, Serialize)]
+pub(crate) enum Pane {
+    Calculation(calculation::Pane),
+    Comparison(comparison::Pane),
+    Radar(radar::Pane),
+    Ranking(ranking::Pane),
+}
+
+impl Pane {
+    pub(crate) const fn calculation(frame: MetaDataFrame) -> Self {
+        Self::Calculation(calculation::Pane::new(frame))
+    }
+
+    pub(crate) const fn comparison() -> Self {
+        Self::Comparison(comparison::Pane::new())
+    }
+
+    pub(crate) const fn radar() -> Self {
+        Self::Radar(radar::Pane::new())
+    }
+
+    pub(crate) const fn ranking() -> Self {
+        Self::Ranking(ranking::Pane::new())
+    }
+
+    pub(crate) fn title(&self) -> String {
+        match self {
+            Self::Calculation(pane) => pane.title(),
+            Self::Comparison(pane) => pane.title(),
+            Self::Radar(pane) => pane.title(),
+            Self::Ranking(pane) => pane.title(),
+        }
+    }
+
+    pub(crate) fn header(&mut self, ui: &mut Ui) -> Response {
+        match self {
+            Self::Calculation(pane) => pane.header(ui),
+            Self::Comparison(pane) => pane.header(ui),
+            Self::Radar(pane) => pane.header(ui),
+            Self::Ranking(pane) => pane.header(ui),
+        }
+    }
+
+    pub(crate) fn body(&mut self, ui: &mut Ui, samples: &[Sample]) {
+        match self {
+            Self::Calculation(pane) => pane.body(ui),
+            Self::Comparison(pane) => pane.body(ui, samples),
+            Self::Radar(pane) => pane.body(ui, samples),
+            Self::Ranking(pane) => pane.body(ui, samples),
+        }
+    }
+
+    pub(crate) fn resizable(&self) -> bool {
+        match self {
+            Self::Calculation(pane) => pane.settings.resizable,
+            Self::Comparison(pane) => pane.settings.resizable,
+            Self::Radar(_) => false,
+            Self::Ranking(pane) => pane.settings.resizable,
+        }
+    }
+
+    pub(crate) fn set_resizable(&mut self, resizable: bool) {
+        match self {
+            Self::Calculation(pane) => pane.settings.resizable = resizable,
+            Self::Comparison(pane) => pane.settings.resizable = resizable,
+            Self::Radar(_) => {}
+            Self::Ranking(pane) => pane.settings.resizable = resizable,
+        }
+    }
+
+    pub(crate) fn sample(&self) -> Option<Sample> {
+        match self {
+            Self::Calculation(pane) => Some(pane.sample()),
+            Self::Comparison(_) | Self::Radar(_) | Self::Ranking(_) => None,
+        }
+    }
+}
 
 pub(crate) mod behavior;
 pub(crate) mod calculation;
+pub(crate) mod comparison;
+pub(crate) mod radar;
+pub(crate) mod ranking;
+pub(crate) mod sample;
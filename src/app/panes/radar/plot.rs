@@ -0,0 +1,241 @@
+use super::{ID_SOURCE, Settings};
+use crate::{
+    app::panes::Sample, localization::localize, presets::_10_1021_jf903048p::MATURE_MILK_FAT,
+};
+use egui::{Id, Ui};
+use egui_plot::{Legend, Line, LineStyle, Plot, PlotPoint, PlotPoints, Points, Polygon, Text};
+use lipid::fatty_acid::{
+    display::{COMMON, DisplayWithOptions},
+    polars::DataFrameExt as _,
+};
+use polars::prelude::*;
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+const STEP: f64 = 0.5;
+
+/// Radar plot view
+pub(super) struct PlotView<'a> {
+    samples: &'a [Sample],
+    settings: &'a Settings,
+}
+
+impl<'a> PlotView<'a> {
+    pub(super) fn new(samples: &'a [Sample], settings: &'a Settings) -> Self {
+        Self { samples, settings }
+    }
+}
+
+impl PlotView<'_> {
+    pub(super) fn show(&self, ui: &mut Ui) {
+        if let Err(error) = self.try_show(ui) {
+            ui.label(error.to_string());
+        }
+    }
+
+    fn try_show(&self, ui: &mut Ui) -> PolarsResult<()> {
+        let reference = reference(self.settings.threshold)?;
+        if reference.labels.len() < 3 || self.settings.samples.is_empty() {
+            ui.label(localize!("radar.description"));
+            return Ok(());
+        }
+        let mut profiles = Vec::with_capacity(self.settings.samples.len());
+        for title in &self.settings.samples {
+            if let Some(sample) = Sample::find(self.samples, title) {
+                profiles.push(Profile::new(&sample, &reference)?);
+            }
+        }
+        ui.columns(2, |ui| {
+            self.plot(
+                &mut ui[0],
+                "SN123",
+                &reference.labels,
+                &profiles,
+                |profile| &profile.sn123,
+            );
+            self.plot(&mut ui[1], "SN2", &reference.labels, &profiles, |profile| {
+                &profile.sn2
+            });
+        });
+        Ok(())
+    }
+
+    fn plot(
+        &self,
+        ui: &mut Ui,
+        name: &str,
+        labels: &[String],
+        profiles: &[Profile],
+        values: impl Fn(&Profile) -> &Vec<f64>,
+    ) {
+        let count = labels.len();
+        let max = profiles
+            .iter()
+            .flat_map(|profile| values(profile))
+            .copied()
+            .fold(1.0, f64::max);
+        let max = (max / STEP).ceil() * STEP;
+        let precision = self.settings.precision;
+        let hover = labels.to_vec();
+        ui.vertical_centered(|ui| ui.heading(name));
+        Plot::new(Id::new(ID_SOURCE).with(name))
+            .data_aspect(1.0)
+            .show_axes(false)
+            .show_grid(false)
+            .allow_drag(false)
+            .allow_scroll(false)
+            .allow_zoom(false)
+            .legend(Legend::default())
+            .label_formatter(move |name, point| {
+                if name.is_empty() {
+                    return String::new();
+                }
+                let index = nearest(point.x, point.y, hover.len());
+                format!(
+                    "{name}\n{}: {:.precision$}",
+                    hover[index],
+                    point.x.hypot(point.y),
+                )
+            })
+            .show(ui, |ui| {
+                // Grid
+                let mut radius = STEP;
+                while radius <= max {
+                    let line = Line::new(circle(radius, count))
+                        .color(ui.ctx().style().visuals.weak_text_color())
+                        .width(0.5);
+                    ui.line(if radius == 1.0 {
+                        line.name(localize!("reference")).width(1.5)
+                    } else {
+                        line.style(LineStyle::dotted_dense())
+                    });
+                    radius += STEP;
+                }
+                for (index, label) in labels.iter().enumerate() {
+                    let [x, y] = point(max, index, count);
+                    ui.line(
+                        Line::new(PlotPoints::new(vec![[0.0, 0.0], [x, y]]))
+                            .color(ui.ctx().style().visuals.weak_text_color())
+                            .width(0.5),
+                    );
+                    let [x, y] = point(max + STEP / 2.0, index, count);
+                    ui.text(Text::new(PlotPoint::new(x, y), label));
+                }
+                // Profiles
+                for profile in profiles {
+                    let points = values(profile)
+                        .iter()
+                        .enumerate()
+                        .map(|(index, &radius)| point(radius, index, count))
+                        .collect::<Vec<_>>();
+                    ui.polygon(Polygon::new(PlotPoints::new(points.clone())).name(&profile.title));
+                    ui.points(
+                        Points::new(PlotPoints::new(points))
+                            .name(&profile.title)
+                            .radius(3.0),
+                    );
+                }
+            });
+    }
+}
+
+/// Major fatty acids of the reference
+struct Reference {
+    labels: Vec<String>,
+    sn123: Vec<f64>,
+    sn2: Vec<f64>,
+}
+
+fn reference(threshold: f64) -> PolarsResult<Reference> {
+    let data_frame = &MATURE_MILK_FAT.data;
+    let fatty_acids = data_frame.fatty_acid();
+    let sn123 = data_frame["StereospecificNumber123"]
+        .struct_()?
+        .field_by_name("Median")?;
+    let sn2 = data_frame["StereospecificNumber2"]
+        .struct_()?
+        .field_by_name("Absolute")?
+        .struct_()?
+        .field_by_name("Median")?;
+    let mut reference = Reference {
+        labels: Vec::new(),
+        sn123: Vec::new(),
+        sn2: Vec::new(),
+    };
+    for (index, (sn123, sn2)) in sn123.f64()?.iter().zip(sn2.f64()?).enumerate() {
+        let (Some(sn123), Some(sn2)) = (sn123, sn2) else {
+            continue;
+        };
+        if sn123 < threshold || sn123 == 0.0 || sn2 == 0.0 {
+            continue;
+        }
+        let Some(fatty_acid) = fatty_acids.get(index)? else {
+            continue;
+        };
+        reference
+            .labels
+            .push(format!("{:#}", (&fatty_acid).display(COMMON)));
+        reference.sn123.push(sn123);
+        reference.sn2.push(sn2);
+    }
+    Ok(reference)
+}
+
+/// Sample profile normalized to the reference median
+struct Profile {
+    title: String,
+    sn123: Vec<f64>,
+    sn2: Vec<f64>,
+}
+
+impl Profile {
+    fn new(sample: &Sample, reference: &Reference) -> PolarsResult<Self> {
+        let mut profile = Self {
+            title: sample.title.clone(),
+            sn123: Vec::with_capacity(reference.labels.len()),
+            sn2: Vec::with_capacity(reference.labels.len()),
+        };
+        let labels = sample.labels()?;
+        let data_frame = &sample.frame.data;
+        let (sn123, sn2) = if data_frame.is_empty() {
+            (Vec::new(), Vec::new())
+        } else {
+            (
+                data_frame["StereospecificNumber123"].f64()?.to_vec(),
+                data_frame["StereospecificNumber2"].f64()?.to_vec(),
+            )
+        };
+        for (index, label) in reference.labels.iter().enumerate() {
+            let position = labels
+                .iter()
+                .position(|other| other.as_ref() == Some(label));
+            let value = |values: &[Option<f64>]| {
+                position
+                    .and_then(|position| values[position])
+                    .unwrap_or_default()
+            };
+            profile.sn123.push(value(&sn123) / reference.sn123[index]);
+            profile.sn2.push(value(&sn2) / reference.sn2[index]);
+        }
+        Ok(profile)
+    }
+}
+
+/// Point on the axis of the fatty acid, the first axis points up
+fn point(radius: f64, index: usize, count: usize) -> [f64; 2] {
+    let angle = FRAC_PI_2 - TAU * index as f64 / count as f64;
+    [radius * angle.cos(), radius * angle.sin()]
+}
+
+/// Axis nearest to the point
+fn nearest(x: f64, y: f64, count: usize) -> usize {
+    let angle = (FRAC_PI_2 - y.atan2(x)).rem_euclid(TAU);
+    (angle / TAU * count as f64).round() as usize % count
+}
+
+fn circle(radius: f64, count: usize) -> PlotPoints {
+    PlotPoints::new(
+        (0..=count)
+            .map(|index| point(radius, index % count, count))
+            .collect(),
+    )
+}
@@ -0,0 +1,56 @@
+use super::ID_SOURCE;
+use crate::{
+    app::{MAX_PRECISION, panes::Sample, widgets::SamplesWidget},
+    localization::localize,
+};
+use egui::{DragValue, Grid, Id, Slider, Ui, Widget};
+use serde::{Deserialize, Serialize};
+
+/// Settings
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct Settings {
+    pub(crate) precision: usize,
+
+    /// Minimal reference median of a major fatty acid
+    pub(crate) threshold: f64,
+    /// Overlaid samples
+    pub(crate) samples: Vec<String>,
+}
+
+impl Settings {
+    pub(crate) const fn new() -> Self {
+        Self {
+            precision: 2,
+            threshold: 1.0,
+            samples: Vec::new(),
+        }
+    }
+
+    pub(crate) fn show(&mut self, ui: &mut Ui, samples: &[Sample]) {
+        let id_salt = Id::new(ID_SOURCE).with("Settings");
+        Grid::new(id_salt).show(ui, |ui| {
+            // Precision
+            ui.label(localize!("precision"));
+            Slider::new(&mut self.precision, 0..=MAX_PRECISION).ui(ui);
+            ui.end_row();
+
+            // Threshold
+            ui.label(localize!("threshold"))
+                .on_hover_text(localize!("threshold.description"));
+            DragValue::new(&mut self.threshold)
+                .range(0.0..=100.0)
+                .speed(0.1)
+                .ui(ui);
+            ui.end_row();
+        });
+        ui.separator();
+        // Samples
+        SamplesWidget::new(&mut self.samples, samples).ui(ui);
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
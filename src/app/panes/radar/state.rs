@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub(crate) struct State {
+    pub(crate) open_settings_window: bool,
+}
+
+impl State {
+    pub(crate) const fn new() -> Self {
+        Self {
+            open_settings_window: false,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use super::ID_SOURCE;
+use crate::{
+    app::MAX_PRECISION,
+    localization::localize,
+    presets::{_10_1021_jf903048p::MATURE_MILK_FAT, PRESETS},
+};
+use egui::{Grid, Id, ScrollArea, Slider, Ui, Widget};
+use lipid::fatty_acid::{
+    display::{COMMON, DisplayWithOptions},
+    polars::DataFrameExt as _,
+};
+use serde::{Deserialize, Serialize};
+
+/// Settings
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) struct Settings {
+    #[serde(skip)]
+    pub(crate) resizable: bool,
+    pub(crate) precision: usize,
+    pub(crate) sticky: usize,
+    pub(crate) truncate: bool,
+
+    pub(crate) panes: bool,
+    pub(crate) presets: Vec<String>,
+    pub(crate) fatty_acids: Vec<String>,
+}
+
+impl Settings {
+    pub(crate) const fn new() -> Self {
+        Self {
+            resizable: false,
+            precision: 2,
+            sticky: 2,
+            truncate: false,
+            panes: true,
+            presets: Vec::new(),
+            fatty_acids: Vec::new(),
+        }
+    }
+
+    pub(crate) fn show(&mut self, ui: &mut Ui) {
+        let id_salt = Id::new(ID_SOURCE).with("Settings");
+        Grid::new(id_salt).show(ui, |ui| {
+            // Precision
+            ui.label(localize!("precision"));
+            Slider::new(&mut self.precision, 0..=MAX_PRECISION).ui(ui);
+            ui.end_row();
+
+            ui.separator();
+            ui.separator();
+            ui.end_row();
+
+            // Panes
+            ui.label(localize!("panes"));
+            ui.checkbox(&mut self.panes, "")
+                .on_hover_text(localize!("panes_description"));
+            ui.end_row();
+        });
+        ui.separator();
+        ScrollArea::vertical().show(ui, |ui| {
+            // Presets
+            ui.collapsing(localize!("presets"), |ui| {
+                for preset in PRESETS {
+                    toggle(ui, &mut self.presets, preset.meta.title());
+                }
+            });
+            // Fatty acids
+            ui.collapsing(localize!("fatty_acids"), |ui| {
+                let fatty_acids = MATURE_MILK_FAT.data.fatty_acid();
+                for index in 0..fatty_acids.len() {
+                    if let Ok(Some(fatty_acid)) = fatty_acids.get(index) {
+                        let label = format!("{:#}", (&fatty_acid).display(COMMON));
+                        toggle(ui, &mut self.fatty_acids, label);
+                    }
+                }
+            });
+        });
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn toggle(ui: &mut Ui, values: &mut Vec<String>, value: String) {
+    let mut checked = values.contains(&value);
+    if ui.checkbox(&mut checked, &value).changed() {
+        if checked {
+            values.push(value);
+        } else {
+            values.retain(|item| *item != value);
+        }
+    }
+}
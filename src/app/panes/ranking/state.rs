@@ -0,0 +1,58 @@
+use crate::utils::Markup;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub(crate) struct State {
+    pub(crate) open_settings_window: bool,
+    pub(crate) reset_table_state: bool,
+    pub(crate) sort: Sort,
+    /// Export of the table requested
+    #[serde(skip)]
+    pub(crate) export: Option<Markup>,
+    /// Report requested
+    #[serde(skip)]
+    pub(crate) report: bool,
+}
+
+impl State {
+    pub(crate) const fn new() -> Self {
+        Self {
+            open_settings_window: false,
+            reset_table_state: false,
+            sort: Sort::new(),
+            export: None,
+            report: false,
+        }
+    }
+}
+
+/// Sort
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct Sort {
+    pub(crate) column: Option<usize>,
+    pub(crate) descending: bool,
+}
+
+impl Sort {
+    pub(crate) const fn new() -> Self {
+        Self {
+            column: None,
+            descending: true,
+        }
+    }
+
+    pub(crate) fn toggle(&mut self, column: usize) {
+        if self.column == Some(column) {
+            self.descending ^= true;
+        } else {
+            self.column = Some(column);
+            self.descending = true;
+        }
+    }
+}
+
+impl Default for Sort {
+    fn default() -> Self {
+        Self::new()
+    }
+}
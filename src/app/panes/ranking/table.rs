@@ -0,0 +1,269 @@
+use super::{
+    ID_SOURCE, Settings,
+    state::{Sort, State},
+};
+use crate::{
+    app::{
+        MARGIN,
+        computers::{RankingComputed, RankingKey, RankingRow as Row},
+        panes::Sample,
+        report,
+        widgets::FloatWidget,
+    },
+    localization::localize,
+    utils::{Markup, Table as MarkupTable, export},
+};
+use egui::{Frame, Id, Label, Margin, RichText, Sense, TextStyle, TextWrapMode, Ui, Widget};
+use egui_phosphor::regular::{SORT_ASCENDING, SORT_DESCENDING};
+use egui_table::{
+    AutoSizeMode, CellInfo, Column, HeaderCellInfo, HeaderRow, Table, TableDelegate, TableState,
+};
+use std::{cmp::Ordering, ops::Range};
+use tracing::error;
+
+const ID: Range<usize> = 0..2;
+const FATTY_ACID: &[&str] = &["SN123", "SN2", "F"];
+const SCORE: &[(&str, &str)] = &[("G1", "50 - ∑E"), ("G2", "50 - ∑E"), ("G", "100 - ∑F")];
+
+/// Ranking table view
+pub(super) struct TableView<'a> {
+    samples: &'a [Sample],
+    settings: &'a Settings,
+    state: &'a mut State,
+    rows: Vec<Row>,
+}
+
+impl<'a> TableView<'a> {
+    pub(super) fn new(samples: &'a [Sample], settings: &'a Settings, state: &'a mut State) -> Self {
+        Self {
+            samples,
+            settings,
+            state,
+            rows: Vec::new(),
+        }
+    }
+}
+
+impl TableView<'_> {
+    pub(super) fn show(&mut self, ui: &mut Ui) {
+        self.rows = self.rows(ui);
+        self.sort();
+        if let Some(markup) = self.state.export.take() {
+            let text = self.markup_table().format(markup);
+            if let Err(error) = export(&format!("ranking.{}", markup.extension()), text.as_bytes())
+            {
+                error!(%error);
+            }
+        }
+        if self.state.report {
+            self.state.report = false;
+            let scores = self
+                .rows
+                .iter()
+                .map(|row| (row.title.clone(), row.values.last().copied().flatten()))
+                .collect::<Vec<_>>();
+            let html = report::ranking(
+                &self.markup_table(),
+                &scores,
+                self.settings.precision,
+                env!("CARGO_PKG_VERSION"),
+            );
+            if let Err(error) = export("ranking.html", html.as_bytes()) {
+                error!(%error);
+            }
+        }
+        let id_salt = Id::new(ID_SOURCE).with("Table");
+        if self.state.reset_table_state {
+            let id = TableState::id(ui, Id::new(id_salt));
+            TableState::reset(ui.ctx(), id);
+            self.state.reset_table_state = false;
+        }
+        let height = ui.text_style_height(&TextStyle::Heading);
+        let num_rows = self.rows.len() as _;
+        let num_columns = self.scores().end;
+        let mut groups = vec![ID];
+        for index in 0..self.settings.fatty_acids.len() {
+            groups.push(self.fatty_acid(index));
+        }
+        groups.push(self.scores());
+        Table::new()
+            .id_salt(id_salt)
+            .num_rows(num_rows)
+            .columns(vec![
+                Column::default().resizable(self.settings.resizable);
+                num_columns
+            ])
+            .num_sticky_cols(self.settings.sticky)
+            .headers([HeaderRow { height, groups }, HeaderRow::new(height)])
+            .auto_size_mode(AutoSizeMode::OnParentResize)
+            .show(ui, self);
+    }
+
+    fn fatty_acid(&self, index: usize) -> Range<usize> {
+        let start = ID.end + index * FATTY_ACID.len();
+        start..start + FATTY_ACID.len()
+    }
+
+    fn scores(&self) -> Range<usize> {
+        let start = ID.end + self.settings.fatty_acids.len() * FATTY_ACID.len();
+        start..start + SCORE.len()
+    }
+
+    /// Rows of the open panes and the selected presets (cached)
+    fn rows(&self, ui: &Ui) -> Vec<Row> {
+        ui.memory_mut(|memory| {
+            memory.caches.cache::<RankingComputed>().get(RankingKey {
+                samples: self.samples,
+                settings: self.settings,
+            })
+        })
+    }
+
+    /// Rows in the displayed order with the grouped header
+    fn markup_table(&self) -> MarkupTable {
+        let mut groups = vec![("ID".to_owned(), ID.len())];
+        let mut names = vec!["#".to_owned(), localize!("sample")];
+        for fatty_acid in &self.settings.fatty_acids {
+            groups.push((fatty_acid.clone(), FATTY_ACID.len()));
+            names.extend(FATTY_ACID.iter().map(|name| name.to_string()));
+        }
+        groups.push((localize!("score"), SCORE.len()));
+        names.extend(SCORE.iter().map(|(name, _)| name.to_string()));
+        let precision = self.settings.precision;
+        let rows = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| {
+                [(index + 1).to_string(), row.title.clone()]
+                    .into_iter()
+                    .chain(row.values.iter().map(|value| {
+                        value.map_or_else(String::new, |value| format!("{value:.precision$}"))
+                    }))
+                    .collect()
+            })
+            .collect();
+        MarkupTable {
+            groups,
+            names,
+            rows,
+        }
+    }
+
+    fn sort(&mut self) {
+        let Sort { column, descending } = self.state.sort;
+        let Some(column) = column else {
+            return;
+        };
+        self.rows.sort_by(|left, right| {
+            let ordering = if column < ID.end {
+                left.title.cmp(&right.title)
+            } else {
+                let index = column - ID.end;
+                match (left.values[index], right.values[index]) {
+                    (Some(left), Some(right)) => left.total_cmp(&right),
+                    (Some(_), None) => return Ordering::Less,
+                    (None, Some(_)) => return Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
+            };
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+
+    fn header_cell_content_ui(&mut self, ui: &mut Ui, row: usize, column: Range<usize>) {
+        if self.settings.truncate {
+            ui.style_mut().wrap_mode = Some(TextWrapMode::Truncate);
+        }
+        let scores = self.scores();
+        match row {
+            // Top
+            0 if column == ID => {
+                ui.heading("ID");
+            }
+            0 if column == scores => {
+                ui.heading(localize!("score"));
+            }
+            0 => {
+                let index = (column.start - ID.end) / FATTY_ACID.len();
+                ui.heading(&self.settings.fatty_acids[index]);
+            }
+            // Bottom
+            _ => {
+                let column = column.start;
+                if column == ID.start {
+                    ui.heading("#");
+                    return;
+                }
+                let (text, hover) = if column < ID.end {
+                    (localize!("sample"), None)
+                } else if column < scores.start {
+                    let name = FATTY_ACID[(column - ID.end) % FATTY_ACID.len()];
+                    (name.to_owned(), None)
+                } else {
+                    let (name, hover) = SCORE[column - scores.start];
+                    (name.to_owned(), Some(hover))
+                };
+                let mut text = RichText::new(text).heading();
+                if self.state.sort.column == Some(column) {
+                    let icon = if self.state.sort.descending {
+                        SORT_DESCENDING
+                    } else {
+                        SORT_ASCENDING
+                    };
+                    text = RichText::new(format!("{} {icon}", text.text())).heading();
+                }
+                let mut response = Label::new(text).sense(Sense::click()).ui(ui);
+                if let Some(hover) = hover {
+                    response = response.on_hover_text(hover);
+                }
+                if response.clicked() {
+                    self.state.sort.toggle(column);
+                }
+            }
+        }
+    }
+
+    fn cell_content_ui(&mut self, ui: &mut Ui, row: usize, column: usize) {
+        let Some(values) = self.rows.get(row) else {
+            return;
+        };
+        if column == ID.start {
+            ui.label((row + 1).to_string());
+        } else if column < ID.end {
+            ui.label(&values.title).on_hover_text(&values.title);
+        } else {
+            let value = values.values[column - ID.end];
+            FloatWidget::new(|| Ok(value))
+                .precision(Some(self.settings.precision))
+                .hover()
+                .ui(ui);
+        }
+    }
+}
+
+impl TableDelegate for TableView<'_> {
+    fn header_cell_ui(&mut self, ui: &mut Ui, cell: &HeaderCellInfo) {
+        Frame::none()
+            .inner_margin(Margin::symmetric(MARGIN.x, MARGIN.y))
+            .show(ui, |ui| {
+                self.header_cell_content_ui(ui, cell.row_nr, cell.col_range.clone())
+            });
+    }
+
+    fn cell_ui(&mut self, ui: &mut Ui, cell: &CellInfo) {
+        if cell.row_nr % 2 == 0 {
+            ui.painter()
+                .rect_filled(ui.max_rect(), 0.0, ui.visuals().faint_bg_color);
+        }
+        Frame::none()
+            .inner_margin(Margin::symmetric(MARGIN.x, MARGIN.y))
+            .show(ui, |ui| {
+                self.cell_content_ui(ui, cell.row_nr as _, cell.col_nr)
+            });
+    }
+}
@@ -0,0 +1,117 @@
+use super::calculation::settings::Settings;
+use crate::{
+    app::computers::{CalculationComputed, CalculationKey},
+    presets::{HASHES, PRESETS},
+};
+use egui::Ui;
+use lipid::fatty_acid::{
+    display::{COMMON, DisplayWithOptions},
+    polars::DataFrameExt as _,
+};
+use metadata::MetaDataFrame;
+use polars::prelude::*;
+
+/// Sample
+///
+/// A snapshot of a calculation pane shared with panes that aggregate several
+/// samples.
+#[derive(Clone)]
+pub(crate) struct Sample {
+    pub(crate) title: String,
+    pub(crate) frame: MetaDataFrame,
+    /// Content hash of the data
+    pub(crate) hash: u64,
+    pub(crate) settings: Settings,
+}
+
+impl Sample {
+    /// Preset of the index in [`PRESETS`]
+    pub(crate) fn preset(index: usize) -> Self {
+        let frame = &**PRESETS[index];
+        Self {
+            title: frame.meta.title(),
+            frame: frame.clone(),
+            hash: HASHES[index],
+            settings: Settings::new(),
+        }
+    }
+
+    /// Finds an open pane or a preset by title
+    pub(crate) fn find(samples: &[Sample], title: &str) -> Option<Self> {
+        if let Some(sample) = samples.iter().find(|sample| sample.title == title) {
+            return Some(sample.clone());
+        }
+        PRESETS
+            .iter()
+            .position(|preset| preset.meta.title() == title)
+            .map(Self::preset)
+    }
+
+    /// Calculated table (cached)
+    pub(crate) fn target(&self, ui: &Ui) -> DataFrame {
+        ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<CalculationComputed>()
+                .get(CalculationKey {
+                    data_frame: &self.frame.data,
+                    hash: self.hash,
+                    settings: &self.settings,
+                })
+        })
+    }
+
+    /// Fatty acid labels
+    pub(crate) fn labels(&self) -> PolarsResult<Vec<Option<String>>> {
+        labels(&self.frame.data)
+    }
+}
+
+/// Fatty acid labels of a data frame
+pub(crate) fn labels(data_frame: &DataFrame) -> PolarsResult<Vec<Option<String>>> {
+    let fatty_acids = data_frame.fatty_acid();
+    let mut labels = Vec::with_capacity(fatty_acids.len());
+    for index in 0..fatty_acids.len() {
+        labels.push(
+            fatty_acids
+                .get(index)?
+                .map(|fatty_acid| format!("{:#}", (&fatty_acid).display(COMMON))),
+        );
+    }
+    Ok(labels)
+}
+
+/// Scores (G1, G2, G) of a calculated table
+pub(crate) fn scores(target: &DataFrame) -> PolarsResult<[Option<f64>; 3]> {
+    let e = |name: &str| -> PolarsResult<Option<f64>> {
+        Ok(target[name]
+            .struct_()?
+            .field_by_name("Data")?
+            .struct_()?
+            .field_by_name("E")?
+            .f64()?
+            .sum())
+    };
+    Ok([
+        e("StereospecificNumber123")?.map(|e| 50.0 - e),
+        e("StereospecificNumber2")?.map(|e| 50.0 - e),
+        target["F"].f64()?.sum().map(|f| 100.0 - f),
+    ])
+}
+
+/// Field of a calculated table, `position` is `StereospecificNumber123` or
+/// `StereospecificNumber2`, `group` is `Data` or `Meta`
+pub(crate) fn field(
+    target: &DataFrame,
+    position: &str,
+    group: &str,
+    name: &str,
+) -> PolarsResult<Vec<Option<f64>>> {
+    Ok(target[position]
+        .struct_()?
+        .field_by_name(group)?
+        .struct_()?
+        .field_by_name(name)?
+        .f64()?
+        .to_vec())
+}
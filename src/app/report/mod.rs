@@ -0,0 +1,216 @@
+use self::svg::{Series, bars};
+use crate::{
+    app::panes::{
+        calculation::{flatten, table},
+        sample::{field, scores},
+    },
+    localization::localize,
+    presets::_10_1021_jf903048p::MATURE_MILK_FAT,
+    utils::{Table, doi, escape_html},
+};
+use metadata::MetaDataFrame;
+use polars::prelude::*;
+use std::fmt::Write;
+
+const STYLE: &str = "\
+body { font-family: sans-serif; margin: 2em; color: #222; }
+h1 { margin-bottom: 0; }
+table { border-collapse: collapse; margin: 1em 0; font-size: 0.9em; }
+th, td { border: 1px solid #ccc; padding: 0.2em 0.5em; text-align: right; }
+th { background: #f0f0f0; text-align: center; }
+td:nth-child(2) { text-align: left; }
+dl { display: grid; grid-template-columns: max-content auto; gap: 0.2em 1em; }
+dt { font-weight: bold; }
+dd { margin: 0; }
+svg { display: block; margin: 1em 0; }
+footer { margin-top: 2em; color: #777; font-size: 0.8em; }
+@media print { section { break-inside: avoid; } }
+";
+
+/// Self-contained HTML report of a calculation pane
+///
+/// Holds the metadata, the input, the calculated table with the totals, SVG
+/// charts of E and F, the reference with its DOI and the version of the
+/// application.
+pub(crate) fn sample(
+    frame: &MetaDataFrame,
+    target: &DataFrame,
+    precision: usize,
+    version: &str,
+) -> PolarsResult<String> {
+    let title = frame.meta.title();
+    let mut html = head(&title, version);
+    // Metadata
+    let mut metadata = vec![(localize!("name"), escape_html(&frame.meta.name))];
+    if let Some(version) = &frame.meta.version {
+        metadata.push((localize!("version"), version.to_string()));
+    }
+    if !frame.meta.description.is_empty() {
+        metadata.push((
+            localize!("description"),
+            escape_html(&frame.meta.description),
+        ));
+    }
+    if let Some(doi) = doi(&frame.meta.description) {
+        metadata.push(("DOI".to_owned(), link(doi)));
+    }
+    section(&mut html, &localize!("metadata"), &list(&metadata));
+    // Input
+    let flattened = flatten(&frame.data, target)?;
+    let input = Table {
+        groups: vec![("ID".to_owned(), 2), (localize!("experimental"), 2)],
+        names: Vec::from(["Index", "FA", "SN123", "SN2"].map(ToOwned::to_owned)),
+        rows: Table::rows(
+            &flattened
+                .select(["Index", "FA", "Experimental SN123", "Experimental SN2"])?
+                .slice(0, frame.data.height() + 1),
+            precision,
+        )?,
+    };
+    section(&mut html, &localize!("input"), &input.html());
+    // Calculation
+    let calculation = table(&frame.data, target, precision)?;
+    section(&mut html, &localize!("calculation"), &calculation.html());
+    // Totals
+    let [g1, g2, g] = scores(target)?;
+    let f = target["F"].f64()?.sum();
+    let number =
+        |value: Option<f64>| value.map_or_else(String::new, |value| format!("{value:.precision$}"));
+    let totals = [
+        ("∑F".to_owned(), number(f)),
+        ("G1 (50 - ∑E)".to_owned(), number(g1)),
+        ("G2 (50 - ∑E)".to_owned(), number(g2)),
+        ("G (100 - ∑F)".to_owned(), number(g)),
+    ];
+    section(&mut html, &localize!("totals"), &list(&totals));
+    // Charts
+    let labels = (0..frame.data.height())
+        .map(|index| {
+            flattened["FA"]
+                .str()
+                .ok()
+                .and_then(|labels| labels.get(index))
+                .unwrap_or_default()
+                .to_owned()
+        })
+        .collect::<Vec<_>>();
+    let mut charts = bars(
+        "E",
+        &labels,
+        &[
+            Series {
+                name: "SN123",
+                values: field(target, "StereospecificNumber123", "Data", "E")?,
+            },
+            Series {
+                name: "SN2",
+                values: field(target, "StereospecificNumber2", "Data", "E")?,
+            },
+        ],
+        precision,
+    );
+    charts.push_str(&bars(
+        "F",
+        &labels,
+        &[Series {
+            name: "F",
+            values: target["F"].f64()?.to_vec(),
+        }],
+        precision,
+    ));
+    section(&mut html, &localize!("charts"), &charts);
+    reference(&mut html);
+    Ok(tail(html, version))
+}
+
+/// Self-contained HTML report of a ranking
+///
+/// Holds the ranking table, an SVG chart of the scores, the reference with
+/// its DOI and the version of the application.
+pub(crate) fn ranking(
+    table: &Table,
+    scores: &[(String, Option<f64>)],
+    precision: usize,
+    version: &str,
+) -> String {
+    let title = localize!("ranking");
+    let mut html = head(&title, version);
+    section(&mut html, &title, &table.html());
+    let labels = scores
+        .iter()
+        .map(|(title, _)| title.clone())
+        .collect::<Vec<_>>();
+    let chart = bars(
+        "G (100 - ∑F)",
+        &labels,
+        &[Series {
+            name: "G",
+            values: scores.iter().map(|(_, score)| *score).collect(),
+        }],
+        precision,
+    );
+    section(&mut html, &localize!("charts"), &chart);
+    reference(&mut html);
+    tail(html, version)
+}
+
+fn head(title: &str, version: &str) -> String {
+    let title = escape_html(title);
+    format!(
+        "<!DOCTYPE html>\n\
+        <html lang=\"en\">\n\
+        <head>\n\
+        <meta charset=\"utf-8\">\n\
+        <meta name=\"generator\" content=\"HMF {version}\">\n\
+        <title>{title}</title>\n\
+        <style>\n{STYLE}</style>\n\
+        </head>\n\
+        <body>\n\
+        <h1>{title}</h1>\n"
+    )
+}
+
+fn tail(mut html: String, version: &str) -> String {
+    writeln!(html, "<footer>HMF {version}</footer>\n</body>\n</html>").ok();
+    html
+}
+
+fn section(html: &mut String, title: &str, content: &str) {
+    writeln!(
+        html,
+        "<section>\n<h2>{}</h2>\n{content}</section>",
+        escape_html(title),
+    )
+    .ok();
+}
+
+/// Definition list of escaped values
+fn list(items: &[(String, String)]) -> String {
+    let mut html = String::from("<dl>\n");
+    for (term, description) in items {
+        writeln!(html, "<dt>{}</dt><dd>{description}</dd>", escape_html(term)).ok();
+    }
+    html.push_str("</dl>\n");
+    html
+}
+
+fn link(doi: &str) -> String {
+    let doi = escape_html(doi);
+    format!("<a href=\"https://doi.org/{doi}\">{doi}</a>")
+}
+
+/// Reference of the calculation
+fn reference(html: &mut String) {
+    let mut items = vec![(
+        localize!("name"),
+        escape_html(&MATURE_MILK_FAT.meta.title()),
+    )];
+    let doi = doi(&MATURE_MILK_FAT.meta.description).unwrap_or("10.1021/jf903048p");
+    items.push(("DOI".to_owned(), link(doi)));
+    section(html, &localize!("reference"), &list(&items));
+}
+
+mod svg;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,102 @@
+use crate::utils::escape_html;
+use std::fmt::Write;
+
+const WIDTH: f64 = 720.0;
+const LABEL: f64 = 112.0;
+const VALUE: f64 = 56.0;
+const BAR: f64 = 12.0;
+const GAP: f64 = 8.0;
+const HEADER: f64 = 48.0;
+const COLORS: [&str; 3] = ["#4e79a7", "#f28e2b", "#59a14f"];
+
+/// Series of a bar chart
+pub(super) struct Series<'a> {
+    pub(super) name: &'a str,
+    pub(super) values: Vec<Option<f64>>,
+}
+
+/// Horizontal bar chart, one bar per series for each label
+///
+/// Bars start at zero, so negative values grow to the left.
+pub(super) fn bars(title: &str, labels: &[String], series: &[Series], precision: usize) -> String {
+    let values = || {
+        series
+            .iter()
+            .flat_map(|series| series.values.iter().flatten())
+    };
+    let min = values().fold(0.0, |min: f64, value| min.min(*value));
+    let max = values().fold(0.0, |max: f64, value| max.max(*value));
+    let range = if max > min { max - min } else { 1.0 };
+    let plot = WIDTH - LABEL - 2.0 * VALUE;
+    let x = |value: f64| LABEL + VALUE + (value - min) / range * plot;
+    let group = series.len() as f64 * BAR + GAP;
+    let height = HEADER + labels.len() as f64 * group + GAP;
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{height}" viewBox="0 0 {WIDTH} {height}" font-family="sans-serif" font-size="11">"#,
+    )
+    .ok();
+    writeln!(
+        svg,
+        r#"<text x="0" y="16" font-size="14" font-weight="bold">{}</text>"#,
+        escape_html(title),
+    )
+    .ok();
+    // Legend
+    let mut legend = LABEL;
+    for (index, series) in series.iter().enumerate() {
+        let color = COLORS[index % COLORS.len()];
+        writeln!(
+            svg,
+            r#"<rect x="{legend}" y="26" width="10" height="10" fill="{color}"/><text x="{}" y="35">{}</text>"#,
+            legend + 14.0,
+            escape_html(series.name),
+        )
+        .ok();
+        legend += 14.0 + 8.0 * series.name.chars().count() as f64 + 16.0;
+    }
+    // Zero
+    writeln!(
+        svg,
+        r##"<line x1="{0:.1}" y1="{1}" x2="{0:.1}" y2="{height}" stroke="#999"/>"##,
+        x(0.0),
+        HEADER - GAP / 2.0,
+    )
+    .ok();
+    // Bars
+    for (row, label) in labels.iter().enumerate() {
+        let top = HEADER + row as f64 * group;
+        writeln!(
+            svg,
+            r#"<text x="{}" y="{:.1}" text-anchor="end">{}</text>"#,
+            LABEL - 4.0,
+            top + series.len() as f64 * BAR / 2.0 + 4.0,
+            escape_html(label),
+        )
+        .ok();
+        for (index, series) in series.iter().enumerate() {
+            let Some(value) = series.values.get(row).copied().flatten() else {
+                continue;
+            };
+            let color = COLORS[index % COLORS.len()];
+            let y = top + index as f64 * BAR;
+            let (start, end) = (x(0.0).min(x(value)), x(0.0).max(x(value)));
+            let (text, anchor) = if value < 0.0 {
+                (start - 4.0, "end")
+            } else {
+                (end + 4.0, "start")
+            };
+            writeln!(
+                svg,
+                r#"<rect x="{start:.1}" y="{y:.1}" width="{:.1}" height="{:.1}" fill="{color}"/><text x="{text:.1}" y="{:.1}" text-anchor="{anchor}">{value:.precision$}</text>"#,
+                end - start,
+                BAR - 2.0,
+                y + BAR - 3.0,
+            )
+            .ok();
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
@@ -0,0 +1,115 @@
+use super::*;
+use crate::{
+    app::{
+        computers::{CalculationComputed, CalculationKey},
+        panes::calculation::settings::Settings,
+    },
+    presets::{_10_1021_jf903048p::CMF_AF, _10_1038_sj_ejcn_1601470::MMF, PRESETS},
+    utils::hash_data_frame,
+};
+use std::{env, fs, path::Path};
+
+const VERSION: &str = "0.0.0";
+
+fn target(frame: &MetaDataFrame) -> DataFrame {
+    CalculationComputed::default().get(CalculationKey {
+        data_frame: &frame.data,
+        hash: hash_data_frame(&frame.data),
+        settings: &Settings::new(),
+    })
+}
+
+/// Compares the output with its snapshot, writes the snapshot if
+/// `UPDATE_SNAPSHOTS` is set (`UPDATE_SNAPSHOTS=1 cargo test report`)
+fn snapshot(name: &str, actual: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/app/report/snapshots")
+        .join(format!("{name}.html"));
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&path).unwrap_or_else(|error| {
+        panic!(
+            "{name}: {error}, set UPDATE_SNAPSHOTS to write {}",
+            path.display(),
+        )
+    });
+    assert!(
+        actual == expected,
+        "{name} differs from {}, set UPDATE_SNAPSHOTS to update it",
+        path.display(),
+    );
+}
+
+/// No external assets: scripts, style sheets, images or fonts
+fn assert_self_contained(html: &str) {
+    for external in ["<script", "<link", "<img", "src=", "url(", "@import"] {
+        assert!(!html.contains(external), "{external}");
+    }
+}
+
+#[test]
+fn sample_snapshots() -> PolarsResult<()> {
+    for (name, frame) in [("CMF-AF", &*CMF_AF), ("MMF", &*MMF)] {
+        let html = sample(frame, &target(frame), 2, VERSION)?;
+        snapshot(name, &html);
+    }
+    Ok(())
+}
+
+#[test]
+fn sample_contents() -> PolarsResult<()> {
+    for preset in PRESETS {
+        let title = preset.meta.title();
+        let html = sample(preset, &target(preset), 2, VERSION)?;
+        assert_self_contained(&html);
+        assert!(html.starts_with("<!DOCTYPE html>"), "{title}");
+        assert!(html.ends_with("</html>\n"), "{title}");
+        assert!(html.contains(&escape_html(&title)), "{title}");
+        assert_eq!(html.matches("<svg").count(), 2, "{title}");
+        assert_eq!(html.matches("<table>").count(), 2, "{title}");
+        assert!(
+            html.contains("https://doi.org/10.1021/jf903048p"),
+            "{title}"
+        );
+        assert!(html.contains(&format!("HMF {VERSION}")), "{title}");
+        // One body row per fatty acid and the totals
+        let rows = preset.data.height() + 1;
+        let input = &html[html.find("<tbody>").unwrap()..html.find("</tbody>").unwrap()];
+        assert_eq!(input.matches("<tr>").count(), rows, "{title}");
+    }
+    Ok(())
+}
+
+#[test]
+fn ranking_snapshot() -> PolarsResult<()> {
+    let mut scores = Vec::new();
+    let mut rows = Vec::new();
+    for preset in PRESETS.iter().take(4) {
+        let [g1, g2, g] = super::scores(&target(preset))?;
+        let number =
+            |value: Option<f64>| value.map_or_else(String::new, |value| format!("{value:.2}"));
+        rows.push(vec![
+            (rows.len() + 1).to_string(),
+            preset.meta.title(),
+            number(g1),
+            number(g2),
+            number(g),
+        ]);
+        scores.push((preset.meta.title(), g));
+    }
+    let table = Table {
+        groups: vec![("ID".to_owned(), 2), ("Score".to_owned(), 3)],
+        names: ["#", "Sample", "G1", "G2", "G"]
+            .map(ToOwned::to_owned)
+            .into(),
+        rows,
+    };
+    let html = ranking(&table, &scores, 2, VERSION);
+    assert_self_contained(&html);
+    assert_eq!(html.matches("<svg").count(), 1);
+    snapshot("ranking", &html);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use egui::{Color32, Response, RichText, Ui};
+use polars::prelude::*;
+
+const INCREASE: Color32 = Color32::from_rgb(46, 160, 67);
+
+/// Delta widget
+///
+/// Signed difference, an increase is green and a decrease is red.
+pub(crate) struct DeltaWidget {
+    pub(crate) value: Option<f64>,
+    pub(crate) precision: usize,
+}
+
+impl DeltaWidget {
+    pub(crate) fn new(value: Option<f64>) -> Self {
+        Self {
+            value,
+            precision: 0,
+        }
+    }
+
+    pub(crate) fn precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+
+    pub(crate) fn ui(self, ui: &mut Ui) -> Response {
+        let Some(value) = self.value else {
+            return ui.label(AnyValue::Null.to_string());
+        };
+        let precision = self.precision;
+        let mut text = RichText::new(format!("{value:+.precision$}"));
+        if value > 0.0 {
+            text = text.color(INCREASE);
+        } else if value < 0.0 {
+            text = text.color(ui.visuals().error_fg_color);
+        }
+        ui.label(text)
+            .on_hover_text(AnyValue::Float64(value).to_string())
+    }
+}
@@ -1,5 +1,9 @@
-pub(super) use self::{fatty_acid::FattyAcidWidget, float::FloatWidget};
+pub(super) use self::{
+    delta::DeltaWidget, fatty_acid::FattyAcidWidget, float::FloatWidget, samples::SamplesWidget,
+};
 
+mod delta;
 mod fatty_acid;
 mod float;
 pub mod new_fatty_acid;
+mod samples;
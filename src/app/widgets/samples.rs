@@ -0,0 +1,54 @@
+use crate::{app::panes::Sample, localization::localize, presets::PRESETS};
+use egui::{ScrollArea, Ui};
+use egui_phosphor::regular::X;
+
+/// Samples widget
+///
+/// Selected samples with a remove button each, followed by the panes and the
+/// presets to add.
+pub(crate) struct SamplesWidget<'a> {
+    pub(crate) selected: &'a mut Vec<String>,
+    pub(crate) samples: &'a [Sample],
+}
+
+impl<'a> SamplesWidget<'a> {
+    pub(crate) fn new(selected: &'a mut Vec<String>, samples: &'a [Sample]) -> Self {
+        Self { selected, samples }
+    }
+
+    pub(crate) fn ui(self, ui: &mut Ui) {
+        // Selected
+        let mut remove = None;
+        for (index, title) in self.selected.iter().enumerate() {
+            ui.horizontal(|ui| {
+                if ui.small_button(X).clicked() {
+                    remove = Some(index);
+                }
+                ui.label(title);
+            });
+        }
+        if let Some(index) = remove {
+            self.selected.remove(index);
+        }
+        ui.separator();
+        ScrollArea::vertical().show(ui, |ui| {
+            // Panes
+            ui.collapsing(localize!("panes"), |ui| {
+                for sample in self.samples {
+                    if ui.button(&sample.title).clicked() {
+                        self.selected.push(sample.title.clone());
+                    }
+                }
+            });
+            // Presets
+            ui.collapsing(localize!("presets"), |ui| {
+                for preset in PRESETS {
+                    let title = preset.meta.title();
+                    if ui.button(&title).clicked() {
+                        self.selected.push(title);
+                    }
+                }
+            });
+        });
+    }
+}
@@ -0,0 +1,578 @@
+use crate::{
+    localization::localize,
+    utils::{
+        parse_fatty_acid, parse_number, records, resolve_fatty_acid, sample_data_frame,
+        sniff_delimiter,
+    },
+};
+use anyhow::{Context as _, Result, anyhow, bail, ensure};
+use calamine::{Data, Reader, open_workbook_auto_from_rs};
+use egui::{Color32, ComboBox, Context, Grid, Id, RichText, ScrollArea, TextEdit, Ui, Window};
+use egui_phosphor::regular::{CHECK, FILE_CSV, WARNING};
+use lipid::fatty_acid::{
+    FattyAcid,
+    display::{COMMON, DisplayWithOptions},
+};
+use metadata::MetaDataFrame;
+use polars::prelude::*;
+use std::io::Cursor;
+
+const ID_SOURCE: &str = "Import";
+const PREVIEW: usize = 64;
+
+/// Import of delimited text (CSV, TSV) and spreadsheets (XLSX, XLS, ODS)
+///
+/// Columns of the file are mapped to the fatty acid, SN123 and SN2 columns
+/// of a calculation pane. Each selected sheet of a spreadsheet becomes its own
+/// pane, the range and the mapping are common to all of them.
+pub(crate) struct Import {
+    pub(crate) open: bool,
+    name: String,
+    text: String,
+    sheets: Vec<Sheet>,
+    /// Previewed sheet
+    sheet: usize,
+    /// Cell range (`A1:C40`), the used range if empty
+    range: String,
+    delimiter: char,
+    decimal_comma: bool,
+    header: bool,
+    mapping: Mapping,
+    /// Records of the text or of the range of the previewed sheet
+    records: Vec<Vec<String>>,
+    /// Rows of the records without the header
+    rows: Vec<Row>,
+}
+
+impl Default for Import {
+    fn default() -> Self {
+        Self {
+            open: false,
+            name: String::new(),
+            text: String::new(),
+            sheets: Vec::new(),
+            sheet: 0,
+            range: String::new(),
+            delimiter: ',',
+            decimal_comma: false,
+            header: true,
+            mapping: Mapping::default(),
+            records: Vec::new(),
+            rows: Vec::new(),
+        }
+    }
+}
+
+impl Import {
+    /// Opens the wizard for the file content
+    pub(crate) fn load(&mut self, name: &str, text: String) {
+        self.name = [".csv", ".tsv", ".txt"]
+            .into_iter()
+            .find_map(|extension| name.strip_suffix(extension))
+            .unwrap_or(name)
+            .to_owned();
+        self.delimiter = if name.ends_with(".tsv") {
+            '\t'
+        } else {
+            sniff_delimiter(&text)
+        };
+        self.text = text;
+        self.sheets.clear();
+        self.reload();
+        self.open = true;
+    }
+
+    /// Opens the wizard for the spreadsheet content
+    pub(crate) fn load_spreadsheet(&mut self, name: &str, bytes: Vec<u8>) -> Result<()> {
+        let mut workbook = open_workbook_auto_from_rs(Cursor::new(bytes))
+            .with_context(|| format!("{name}: unsupported spreadsheet"))?;
+        let mut sheets = Vec::new();
+        for sheet in workbook.sheet_names() {
+            let range = workbook.worksheet_range(&sheet)?;
+            let (row, column) = range.start().unwrap_or_default();
+            let cells = range
+                .rows()
+                .map(|row| row.iter().map(Data::to_string).collect())
+                .collect();
+            sheets.push(Sheet {
+                name: sheet,
+                start: (row as _, column as _),
+                cells,
+                selected: true,
+            });
+        }
+        ensure!(!sheets.is_empty(), "{name}: no sheets");
+        self.name = name
+            .rsplit_once('.')
+            .map_or(name, |(name, _)| name)
+            .to_owned();
+        self.text.clear();
+        self.sheets = sheets;
+        self.sheet = 0;
+        self.range.clear();
+        self.reload();
+        self.open = true;
+        Ok(())
+    }
+
+    /// Reads the records of the text or of the range of the previewed sheet,
+    /// then guesses the settings and parses the rows
+    fn reload(&mut self) {
+        self.records = match self.sheets.get(self.sheet) {
+            Some(sheet) => sheet.records(parse_range(&self.range).ok().flatten()),
+            None => records(&self.text, self.delimiter),
+        };
+        self.guess();
+        self.parse();
+    }
+
+    /// Parses the rows of the records
+    fn parse(&mut self) {
+        self.rows = self.rows(self.body(&self.records));
+    }
+
+    /// Guesses the decimal separator, the header and the mapping
+    fn guess(&mut self) {
+        let records = &self.records;
+        // Decimal comma if a comma is not the delimiter and numbers contain it
+        self.decimal_comma = self.delimiter != ','
+            && records.iter().flatten().any(|field| {
+                field.contains(',')
+                    && parse_number(field, true).is_ok_and(|number| number.is_some())
+            });
+        let Some(first) = records.first() else {
+            self.header = false;
+            self.mapping = Mapping::default();
+            return;
+        };
+        // Header if the first record has no numbers
+        self.header = first
+            .iter()
+            .all(|field| !matches!(parse_number(field, self.decimal_comma), Ok(Some(_))));
+        let mut mapping = Mapping::default();
+        if self.header {
+            for (index, field) in first.iter().enumerate() {
+                let field = field.to_lowercase().replace([' ', '-', ',', '_'], "");
+                if mapping.sn2.is_none()
+                    && (field.contains("sn2") || field.contains("2mag") || field == "mag")
+                {
+                    mapping.sn2 = Some(index);
+                } else if mapping.sn123.is_none()
+                    && (field.contains("sn123")
+                        || field.contains("tag")
+                        || field.contains("area")
+                        || field.contains("total"))
+                {
+                    mapping.sn123 = Some(index);
+                } else if mapping.fatty_acid.is_none()
+                    && (field.contains("fa") || field.contains("acid") || field.contains("label"))
+                {
+                    mapping.fatty_acid = Some(index);
+                }
+            }
+        }
+        let columns = records.iter().map(Vec::len).max().unwrap_or_default();
+        let mut free = (0..columns).filter(|index| !mapping.contains(*index));
+        mapping.fatty_acid = mapping.fatty_acid.or_else(|| free.next());
+        mapping.sn123 = mapping.sn123.or_else(|| free.next());
+        mapping.sn2 = mapping.sn2.or_else(|| free.next());
+        self.mapping = mapping;
+    }
+
+    pub(crate) fn window(&mut self, ctx: &Context) -> Option<PolarsResult<Vec<MetaDataFrame>>> {
+        let mut open = self.open;
+        let mut frames = None;
+        Window::new(format!("{FILE_CSV} {}", localize!("import")))
+            .id(Id::new(ID_SOURCE))
+            .open(&mut open)
+            .default_width(640.0)
+            .show(ctx, |ui| {
+                frames = self.content(ui);
+            });
+        self.open = open && !matches!(frames, Some(Ok(_)));
+        frames
+    }
+
+    fn content(&mut self, ui: &mut Ui) -> Option<PolarsResult<Vec<MetaDataFrame>>> {
+        let columns = self.records.iter().map(Vec::len).max().unwrap_or_default();
+        let names = (0..columns)
+            .map(|index| match self.records.first() {
+                Some(first) if self.header => first
+                    .get(index)
+                    .filter(|name| !name.is_empty())
+                    .cloned()
+                    .unwrap_or_else(|| format!("#{}", index + 1)),
+                _ => format!("#{}", index + 1),
+            })
+            .collect::<Vec<_>>();
+        // Settings
+        let settings = (self.decimal_comma, self.header, self.mapping);
+        let mut changed = false;
+        Grid::new(Id::new(ID_SOURCE).with("Settings")).show(ui, |ui| {
+            if self.sheets.is_empty() {
+                ui.label(localize!("name"));
+                ui.text_edit_singleline(&mut self.name);
+                ui.end_row();
+
+                ui.label(localize!("delimiter"));
+                ui.horizontal(|ui| {
+                    for (delimiter, text) in [(',', ","), (';', ";"), ('\t', "TAB")] {
+                        changed |= ui
+                            .radio_value(&mut self.delimiter, delimiter, text)
+                            .changed();
+                    }
+                });
+                ui.end_row();
+            } else {
+                ui.label(localize!("sheets"))
+                    .on_hover_text(localize!("sheets.description"));
+                ui.vertical(|ui| {
+                    for (index, sheet) in self.sheets.iter_mut().enumerate() {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut sheet.selected, "");
+                            changed |= ui
+                                .selectable_value(&mut self.sheet, index, &sheet.name)
+                                .changed();
+                        });
+                    }
+                });
+                ui.end_row();
+
+                ui.label(localize!("range"));
+                ui.horizontal(|ui| {
+                    changed |= ui
+                        .add(
+                            TextEdit::singleline(&mut self.range)
+                                .hint_text("A1:C40")
+                                .desired_width(96.0),
+                        )
+                        .on_hover_text(localize!("range.description"))
+                        .changed();
+                    if let Err(error) = parse_range(&self.range) {
+                        let color = ui.visuals().error_fg_color;
+                        error_label(ui, &error, color);
+                    }
+                });
+                ui.end_row();
+            }
+
+            ui.label(localize!("decimal_comma"));
+            ui.checkbox(&mut self.decimal_comma, "")
+                .on_hover_text(localize!("decimal_comma.description"));
+            ui.end_row();
+
+            ui.label(localize!("header"));
+            ui.checkbox(&mut self.header, "")
+                .on_hover_text(localize!("header.description"));
+            ui.end_row();
+
+            // Mapping
+            ui.label(localize!("fatty_acid"));
+            column(ui, "FattyAcid", &mut self.mapping.fatty_acid, &names);
+            ui.end_row();
+
+            ui.label("SN123");
+            column(
+                ui,
+                "StereospecificNumber123",
+                &mut self.mapping.sn123,
+                &names,
+            );
+            ui.end_row();
+
+            ui.label("SN2");
+            column(ui, "StereospecificNumber2", &mut self.mapping.sn2, &names);
+            ui.end_row();
+        });
+        if changed {
+            self.reload();
+        } else if settings != (self.decimal_comma, self.header, self.mapping) {
+            self.parse();
+        }
+        if self.sheets.is_empty() {
+            ui.collapsing(localize!("source"), |ui| {
+                ScrollArea::vertical()
+                    .id_salt(Id::new(ID_SOURCE).with("Source"))
+                    .max_height(160.0)
+                    .show(ui, |ui| {
+                        if TextEdit::multiline(&mut self.text)
+                            .code_editor()
+                            .desired_width(f32::INFINITY)
+                            .show(ui)
+                            .response
+                            .changed()
+                        {
+                            self.reload();
+                        }
+                    });
+            });
+        }
+        ui.separator();
+        // Preview
+        let rows = &self.rows;
+        let errors = rows.iter().filter(|row| !row.is_valid()).count();
+        let error_color = ui.visuals().error_fg_color;
+        ScrollArea::both()
+            .id_salt(Id::new(ID_SOURCE).with("Preview"))
+            .max_height(320.0)
+            .show(ui, |ui| {
+                Grid::new(Id::new(ID_SOURCE).with("Preview"))
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.heading("#");
+                        ui.heading(localize!("fatty_acid.abbreviation"));
+                        ui.heading("SN123");
+                        ui.heading("SN2");
+                        ui.end_row();
+                        for (index, row) in rows.iter().take(PREVIEW).enumerate() {
+                            ui.label((index + 1).to_string());
+                            match &row.fatty_acid {
+                                Ok(fatty_acid) => {
+                                    ui.label(format!("{:#}", fatty_acid.display(COMMON)));
+                                }
+                                Err(error) => error_label(ui, error, error_color),
+                            }
+                            for value in [&row.sn123, &row.sn2] {
+                                match value {
+                                    Ok(value) => {
+                                        ui.label(value.to_string());
+                                    }
+                                    Err(error) => error_label(ui, error, error_color),
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+                if rows.len() > PREVIEW {
+                    ui.label(format!("… {}", rows.len() - PREVIEW));
+                }
+            });
+        ui.separator();
+        let valid = rows.len() - errors;
+        ui.horizontal(|ui| {
+            if errors > 0 {
+                ui.label(RichText::new(format!("{WARNING} {errors}")).color(error_color))
+                    .on_hover_text(localize!("invalid_rows"));
+            }
+            let enabled = self.mapping.is_complete()
+                && valid > 0
+                && (self.sheets.is_empty() || self.sheets.iter().any(|sheet| sheet.selected));
+            ui.add_enabled_ui(enabled, |ui| {
+                if ui
+                    .button(format!("{CHECK} {} ({valid})", localize!("import")))
+                    .on_hover_text(localize!("import.description"))
+                    .clicked()
+                {
+                    return Some(self.frames(rows));
+                }
+                None
+            })
+            .inner
+        })
+        .inner
+    }
+
+    /// Records without the header
+    fn body<'a>(&self, records: &'a [Vec<String>]) -> &'a [Vec<String>] {
+        if self.header && !records.is_empty() {
+            &records[1..]
+        } else {
+            records
+        }
+    }
+
+    /// One frame for the text or one per selected sheet
+    fn frames(&self, rows: &[Row]) -> PolarsResult<Vec<MetaDataFrame>> {
+        if self.sheets.is_empty() {
+            return Ok(vec![self.frame(&self.name, rows)?]);
+        }
+        let range = parse_range(&self.range).ok().flatten();
+        let mut frames = Vec::new();
+        for sheet in self.sheets.iter().filter(|sheet| sheet.selected) {
+            let records = sheet.records(range);
+            let rows = self.rows(self.body(&records));
+            frames.push(self.frame(&sheet.name, &rows)?);
+        }
+        Ok(frames)
+    }
+
+    fn rows(&self, records: &[Vec<String>]) -> Vec<Row> {
+        let field = |record: &[String], index: Option<usize>| -> Result<String> {
+            let index = index.ok_or_else(|| anyhow!("unmapped column"))?;
+            Ok(record.get(index).cloned().unwrap_or_default())
+        };
+        let number = |record: &[String], index: Option<usize>| -> Result<f64> {
+            let value = parse_number(&field(record, index)?, self.decimal_comma)?
+                .ok_or_else(|| anyhow!("empty number"))?;
+            ensure!(value.is_finite(), "invalid number `{value}`");
+            Ok(value)
+        };
+        records
+            .iter()
+            .map(|record| Row {
+                fatty_acid: field(record, self.mapping.fatty_acid)
+                    .and_then(|label| parse_fatty_acid(&label))
+                    .map(|fatty_acid| resolve_fatty_acid(fatty_acid)),
+                sn123: number(record, self.mapping.sn123),
+                sn2: number(record, self.mapping.sn2),
+            })
+            .collect()
+    }
+
+    fn frame(&self, name: &str, rows: &[Row]) -> PolarsResult<MetaDataFrame> {
+        let mut fatty_acids = Vec::with_capacity(rows.len());
+        let mut sn123 = Vec::with_capacity(rows.len());
+        let mut sn2 = Vec::with_capacity(rows.len());
+        for row in rows {
+            if let (Ok(fatty_acid), Ok(stereospecific_number123), Ok(stereospecific_number2)) =
+                (&row.fatty_acid, &row.sn123, &row.sn2)
+            {
+                fatty_acids.push(Some(fatty_acid.clone()));
+                sn123.push(*stereospecific_number123);
+                sn2.push(*stereospecific_number2);
+            }
+        }
+        let data = sample_data_frame(&fatty_acids, sn123, sn2)?;
+        let mut frame = MetaDataFrame::new(Default::default(), data);
+        frame.meta.name = name.to_owned();
+        Ok(frame)
+    }
+}
+
+/// Sheet of a spreadsheet
+#[derive(Debug)]
+struct Sheet {
+    name: String,
+    /// Row and column of the first cell
+    start: (usize, usize),
+    cells: Vec<Vec<String>>,
+    selected: bool,
+}
+
+impl Sheet {
+    /// Records of the cell range, the used range if none
+    ///
+    /// The range is clamped to the used range.
+    fn records(&self, range: Option<CellRange>) -> Vec<Vec<String>> {
+        let Some(((top, left), (bottom, right))) = range else {
+            return self.cells.clone();
+        };
+        let (row, column) = self.start;
+        let width = self.cells.iter().map(Vec::len).max().unwrap_or_default();
+        let (Some(bottom), Some(right)) = (
+            (row + self.cells.len())
+                .checked_sub(1)
+                .map(|end| bottom.min(end)),
+            (column + width).checked_sub(1).map(|end| right.min(end)),
+        ) else {
+            return Vec::new();
+        };
+        (top..=bottom)
+            .map(|index| {
+                let cells = index
+                    .checked_sub(row)
+                    .and_then(|index| self.cells.get(index));
+                (left..=right)
+                    .map(|index| {
+                        index
+                            .checked_sub(column)
+                            .and_then(|index| cells?.get(index))
+                            .cloned()
+                            .unwrap_or_default()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .filter(|record| record.iter().any(|field| !field.is_empty()))
+            .collect()
+    }
+}
+
+/// Top left and bottom right cells (row, column)
+type CellRange = ((usize, usize), (usize, usize));
+
+/// Parses an `A1:C40` cell range, none if empty
+fn parse_range(text: &str) -> Result<Option<CellRange>> {
+    let text = text.trim().replace('$', "").to_uppercase();
+    if text.is_empty() {
+        return Ok(None);
+    }
+    let (start, end) = text.split_once(':').unwrap_or((&text, &text));
+    let (top, left) = parse_cell(start)?;
+    let (bottom, right) = parse_cell(end)?;
+    Ok(Some((
+        (top.min(bottom), left.min(right)),
+        (top.max(bottom), left.max(right)),
+    )))
+}
+
+/// Parses an `A1` cell reference to a zero based (row, column)
+fn parse_cell(text: &str) -> Result<(usize, usize)> {
+    let split = text
+        .find(|char: char| !char.is_ascii_uppercase())
+        .with_context(|| format!("invalid cell `{text}`"))?;
+    let (letters, digits) = text.split_at(split);
+    if letters.is_empty() || letters.len() > 3 {
+        bail!("invalid column in `{text}`");
+    }
+    let column = letters.bytes().fold(0, |column, letter| {
+        column * 26 + (letter - b'A' + 1) as usize
+    });
+    let row = digits
+        .parse::<usize>()
+        .ok()
+        .filter(|row| *row > 0)
+        .with_context(|| format!("invalid row in `{text}`"))?;
+    Ok((row - 1, column - 1))
+}
+
+/// Column mapping
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Mapping {
+    fatty_acid: Option<usize>,
+    sn123: Option<usize>,
+    sn2: Option<usize>,
+}
+
+impl Mapping {
+    fn contains(&self, index: usize) -> bool {
+        [self.fatty_acid, self.sn123, self.sn2].contains(&Some(index))
+    }
+
+    fn is_complete(&self) -> bool {
+        self.fatty_acid.is_some() && self.sn123.is_some() && self.sn2.is_some()
+    }
+}
+
+/// Parsed row
+struct Row {
+    fatty_acid: Result<FattyAcid>,
+    sn123: Result<f64>,
+    sn2: Result<f64>,
+}
+
+impl Row {
+    fn is_valid(&self) -> bool {
+        self.fatty_acid.is_ok() && self.sn123.is_ok() && self.sn2.is_ok()
+    }
+}
+
+fn column(ui: &mut Ui, id_salt: &str, selected: &mut Option<usize>, names: &[String]) {
+    let text = selected
+        .and_then(|index| names.get(index))
+        .map_or("", String::as_str);
+    ComboBox::from_id_salt(Id::new(ID_SOURCE).with(id_salt))
+        .selected_text(text)
+        .show_ui(ui, |ui| {
+            for (index, name) in names.iter().enumerate() {
+                ui.selectable_value(selected, Some(index), name);
+            }
+        });
+}
+
+fn error_label(ui: &mut Ui, error: &anyhow::Error, color: Color32) {
+    ui.label(RichText::new(WARNING).color(color))
+        .on_hover_text(error.to_string());
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,53 @@
+use super::*;
+
+#[test]
+fn cell() -> Result<()> {
+    assert_eq!(parse_cell("A1")?, (0, 0));
+    assert_eq!(parse_cell("C40")?, (39, 2));
+    assert_eq!(parse_cell("Z1")?, (0, 25));
+    assert_eq!(parse_cell("AA10")?, (9, 26));
+    assert_eq!(parse_cell("XFD1048576")?, (1048575, 16383));
+    for text in ["", "A", "1", "A0", "1A", "A1B", "AAAA1", "a1", "A-1"] {
+        assert!(parse_cell(text).is_err(), "{text}");
+    }
+    Ok(())
+}
+
+#[test]
+fn range() -> Result<()> {
+    assert_eq!(parse_range("")?, None);
+    assert_eq!(parse_range("  ")?, None);
+    assert_eq!(parse_range("A1:C40")?, Some(((0, 0), (39, 2))));
+    assert_eq!(parse_range("$A$1:$C$40")?, Some(((0, 0), (39, 2))));
+    assert_eq!(parse_range(" b2:aa10 ")?, Some(((1, 1), (9, 26))));
+    assert_eq!(parse_range("B2")?, Some(((1, 1), (1, 1))));
+    // Reversed corners
+    assert_eq!(parse_range("C40:A1")?, Some(((0, 0), (39, 2))));
+    assert_eq!(parse_range("A40:C1")?, Some(((0, 0), (39, 2))));
+    for text in ["A1:", ":C40", "A1:C", "A1-C40", "A1:C40:D50"] {
+        assert!(parse_range(text).is_err(), "{text}");
+    }
+    Ok(())
+}
+
+#[test]
+fn sheet() -> Result<()> {
+    // B2:C3
+    let sheet = Sheet {
+        name: "Sheet1".to_owned(),
+        start: (1, 1),
+        cells: vec![
+            vec!["16:0".to_owned(), "25".to_owned()],
+            vec!["18:1".to_owned(), "40".to_owned()],
+        ],
+        selected: true,
+    };
+    assert_eq!(sheet.records(None), sheet.cells);
+    assert_eq!(sheet.records(parse_range("C3")?), [["40"]]);
+    assert_eq!(sheet.records(parse_range("A1:C2")?), [["", "16:0", "25"]]);
+    // Clamped to the used range
+    let records = sheet.records(parse_range("A1:XFD1048576")?);
+    assert_eq!(records, [["", "16:0", "25"], ["", "18:1", "40"]]);
+    assert!(sheet.records(parse_range("D4:E5")?).is_empty());
+    Ok(())
+}
@@ -1,3 +1,4 @@
-pub(super) use self::about::About;
+pub(super) use self::{about::About, import::Import};
 
 mod about;
+mod import;
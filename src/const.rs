@@ -0,0 +1,18 @@
+/// Relative atomic mass (average)
+pub(crate) mod relative_atomic_mass {
+    pub(crate) const C: f64 = 12.0107;
+    pub(crate) const H: f64 = 1.00794;
+    pub(crate) const O: f64 = 15.9994;
+}
+
+/// Monoisotopic mass
+pub(crate) mod monoisotopic_mass {
+    pub(crate) const C: f64 = 12.0;
+    pub(crate) const H: f64 = 1.00782503207;
+    pub(crate) const N: f64 = 14.0030740048;
+    pub(crate) const NA: f64 = 22.9897692809;
+    pub(crate) const O: f64 = 15.99491461956;
+}
+
+/// Electron mass
+pub(crate) const ELECTRON: f64 = 0.00054857990946;
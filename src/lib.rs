@@ -3,6 +3,7 @@
 pub use self::app::App;
 
 mod app;
+mod r#const;
 mod localization;
 mod presets;
 mod utils;
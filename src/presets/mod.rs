@@ -1,5 +1,6 @@
 // pub(crate) use self::{_10_1016_j_algal_2018_11_004::*, _10_1021_jf903048p::*, ippras::*};
 
+use crate::utils::hash_data_frame;
 use metadata::MetaDataFrame;
 use std::{io::Cursor, sync::LazyLock};
 
@@ -10,6 +11,44 @@ macro preset($name:literal) {
     })
 }
 
+/// Presets
+pub(crate) static PRESETS: &[&LazyLock<MetaDataFrame>] = &[
+    &_10_1038_sj_ejcn_1601470::CMF,
+    &_10_1038_sj_ejcn_1601470::TMF,
+    &_10_1038_sj_ejcn_1601470::MMF,
+    &_10_1021_jf903048p::CMF_AF,
+    &_10_1021_jf903048p::CMF_AP,
+    &_10_1021_jf903048p::CMF_R,
+    &_10_1021_jf903048p::MMF_A,
+    &_10_1016_j_algal_2018_11_004::CV_15,
+    &_10_1016_j_algal_2018_11_004::CZ_30412,
+    &_10_1016_j_algal_2018_11_004::CV_395,
+    &_10_1016_j_algal_2018_11_004::CP_9,
+    &_10_1016_j_algal_2018_11_004::SS,
+    &_10_1016_j_algal_2018_11_004::CS,
+    &_10_1016_j_algal_2018_11_004::NL_2047,
+    &_10_1016_j_algal_2018_11_004::PT_646,
+    &_10_1016_j_algal_2018_11_004::ISO_FJ,
+    &_10_1016_j_algal_2018_11_004::IG_2307,
+    &_10_1016_j_algal_2018_11_004::NO_IMET1,
+    &_10_1016_j_algal_2018_11_004::NS_537,
+    &ippras::C70_CONTROL,
+    &ippras::C70_H2O2,
+    &ippras::C70_NACL,
+    &ippras::H242_N,
+    &ippras::H242_N_1,
+    &ippras::H242_N_2,
+    &ippras::H242_N_3,
+];
+
+/// Content hashes of the presets, see [`hash_data_frame`]
+pub(crate) static HASHES: LazyLock<Vec<u64>> = LazyLock::new(|| {
+    PRESETS
+        .iter()
+        .map(|preset| hash_data_frame(&preset.data))
+        .collect()
+});
+
 /// <https://doi.org/10.1016/j.algal.2018.11.004>
 pub(crate) mod _10_1016_j_algal_2018_11_004 {
     use super::*;
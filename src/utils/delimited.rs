@@ -0,0 +1,130 @@
+use anyhow::{Context as _, Result};
+use polars::prelude::*;
+
+/// Guesses the delimiter of the first line: tab, semicolon or comma
+pub fn sniff_delimiter(text: &str) -> char {
+    let line = text.lines().next().unwrap_or_default();
+    if line.contains('\t') {
+        '\t'
+    } else if line.contains(';') {
+        ';'
+    } else {
+        ','
+    }
+}
+
+/// Splits delimited text into records
+///
+/// Double quoted fields may contain the delimiter and line breaks, a doubled
+/// quote inside them is a quote. Blank lines are skipped.
+pub fn records(text: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted ^= true,
+            _ if quoted => field.push(char),
+            '\r' => {}
+            '\n' => {
+                record.push(field.trim().to_owned());
+                field.clear();
+                if record.iter().any(|field| !field.is_empty()) {
+                    records.push(record);
+                }
+                record = Vec::new();
+            }
+            _ if char == delimiter => {
+                record.push(field.trim().to_owned());
+                field.clear();
+            }
+            _ => field.push(char),
+        }
+    }
+    record.push(field.trim().to_owned());
+    if record.iter().any(|field| !field.is_empty()) {
+        records.push(record);
+    }
+    records
+}
+
+/// Parses a number with a decimal point or comma, empty text is none
+///
+/// Spaces (thousands separators) and a trailing `%` are ignored.
+pub fn parse_number(text: &str, decimal_comma: bool) -> Result<Option<f64>> {
+    let mut number = text
+        .chars()
+        .filter(|char| !char.is_whitespace())
+        .collect::<String>();
+    if let Some(stripped) = number.strip_suffix('%') {
+        number = stripped.to_owned();
+    }
+    if number.is_empty() {
+        return Ok(None);
+    }
+    if decimal_comma {
+        number = number.replace(',', ".");
+    }
+    let number = number.replace('−', "-");
+    Ok(Some(
+        number
+            .parse()
+            .with_context(|| format!("invalid number `{text}`"))?,
+    ))
+}
+
+/// Writes the data frame as delimited text with a header line
+///
+/// Floats are written with the precision if any, fields containing the
+/// delimiter, a quote or a line break are double quoted.
+pub fn to_delimited(
+    data_frame: &DataFrame,
+    delimiter: char,
+    precision: Option<usize>,
+) -> PolarsResult<String> {
+    let quote = |field: &str| {
+        if field.contains([delimiter, '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_owned()
+        }
+    };
+    let mut text = data_frame
+        .get_column_names()
+        .into_iter()
+        .map(|name| quote(name))
+        .collect::<Vec<_>>()
+        .join(&delimiter.to_string());
+    text.push('\n');
+    for row in 0..data_frame.height() {
+        let mut fields = Vec::with_capacity(data_frame.width());
+        for column in data_frame.get_columns() {
+            fields.push(match column.get(row)? {
+                AnyValue::Null => String::new(),
+                AnyValue::Float64(value) => match precision {
+                    Some(precision) => format!("{value:.precision$}"),
+                    None => value.to_string(),
+                },
+                AnyValue::Float32(value) => match precision {
+                    Some(precision) => format!("{value:.precision$}"),
+                    None => value.to_string(),
+                },
+                AnyValue::String(value) => quote(value),
+                AnyValue::StringOwned(value) => quote(&value),
+                value => quote(&value.to_string()),
+            });
+        }
+        text.push_str(&fields.join(&delimiter.to_string()));
+        text.push('\n');
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,63 @@
+use super::*;
+
+#[test]
+fn fields() {
+    assert_eq!(
+        records("FA,SN123,SN2\n16:0, 25 ,40\n", ','),
+        [["FA", "SN123", "SN2"], ["16:0", "25", "40"]],
+    );
+    assert_eq!(records("a\tb;c", '\t'), [["a", "b;c"]]);
+    assert!(records("", ',').is_empty());
+}
+
+#[test]
+fn quoted() {
+    // Delimiter
+    assert_eq!(records("a,\"b,c\"", ','), [["a", "b,c"]]);
+    // Doubled quote
+    assert_eq!(records("\"a \"\"b\"\"\",c", ','), [["a \"b\"", "c"]]);
+    assert_eq!(records("\"\",a", ','), [["", "a"]]);
+    // Line break
+    assert_eq!(records("\"a\nb\",c\nd,e", ','), [["a\nb", "c"], ["d", "e"]]);
+}
+
+#[test]
+fn lines() {
+    // CRLF
+    assert_eq!(records("a,b\r\nc,d\r\n", ','), [["a", "b"], ["c", "d"]]);
+    // Blank lines
+    assert_eq!(
+        records("\na,b\n\n  \n,\nc,d\n\n", ','),
+        [["a", "b"], ["c", "d"]],
+    );
+}
+
+#[test]
+fn number() -> Result<()> {
+    assert_eq!(parse_number("1.5", false)?, Some(1.5));
+    assert_eq!(parse_number("1,5", true)?, Some(1.5));
+    assert_eq!(parse_number("12 %", false)?, Some(12.0));
+    assert_eq!(parse_number("−3.5", false)?, Some(-3.5));
+    assert_eq!(parse_number("-3.5", false)?, Some(-3.5));
+    assert_eq!(parse_number("1 234.5", false)?, Some(1234.5));
+    assert_eq!(parse_number("1 234,5", true)?, Some(1234.5));
+    assert_eq!(parse_number("", false)?, None);
+    assert_eq!(parse_number(" ", false)?, None);
+    assert_eq!(parse_number("%", false)?, None);
+    assert!(parse_number("abc", false).is_err());
+    assert!(parse_number("1,5", false).is_err());
+    Ok(())
+}
+
+#[test]
+fn delimiter() {
+    assert_eq!(sniff_delimiter("FA\tSN123\tSN2"), '\t');
+    assert_eq!(sniff_delimiter("FA;SN123;SN2"), ';');
+    assert_eq!(sniff_delimiter("FA,SN123,SN2"), ',');
+    assert_eq!(sniff_delimiter("FA"), ',');
+    assert_eq!(sniff_delimiter(""), ',');
+    // Tab before semicolon
+    assert_eq!(sniff_delimiter("a;b\tc"), '\t');
+    // Only the first line
+    assert_eq!(sniff_delimiter("FA,SN123\n1;2\t3"), ',');
+}
@@ -0,0 +1,131 @@
+use super::{SCHEMA, fatty_acid_series};
+use anyhow::{Result, bail};
+use lipid::fatty_acid::{
+    FattyAcid, Isomerism, Unsaturated, Unsaturation, polars::DataFrameExt as _,
+};
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Human readable document of a frame (RON, JSON)
+///
+/// The data is written row by row with the nested fatty acids, so that files
+/// are diffable.
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Document<M> {
+    /// Schema version, see [`SCHEMA`]
+    #[serde(default)]
+    pub schema: u32,
+    pub meta: M,
+    pub data: Vec<Row>,
+}
+
+/// Row of a document
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Row {
+    pub fatty_acid: Option<FattyAcidRow>,
+    pub stereospecific_number123: Option<f64>,
+    pub stereospecific_number2: Option<f64>,
+}
+
+/// Fatty acid of a document row
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct FattyAcidRow {
+    pub carbons: u8,
+    pub unsaturated: Vec<UnsaturatedRow>,
+}
+
+/// Unsaturated bond of a document row
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UnsaturatedRow {
+    pub index: Option<u8>,
+    /// Cis (1) or trans (-1)
+    pub isomerism: Option<i8>,
+    /// Double (1) or triple (2)
+    pub unsaturation: Option<u8>,
+}
+
+/// Writes the frame as a document
+pub fn to_document<M>(meta: M, data_frame: &DataFrame) -> Result<Document<M>> {
+    let fatty_acids = data_frame.fatty_acid();
+    let stereospecific_numbers123 = data_frame["StereospecificNumber123"].f64()?;
+    let stereospecific_numbers2 = data_frame["StereospecificNumber2"].f64()?;
+    let mut data = Vec::with_capacity(data_frame.height());
+    for index in 0..data_frame.height() {
+        data.push(Row {
+            fatty_acid: fatty_acids.get(index)?.map(|fatty_acid| FattyAcidRow {
+                carbons: fatty_acid.carbons,
+                unsaturated: fatty_acid
+                    .unsaturated
+                    .iter()
+                    .map(|unsaturated| UnsaturatedRow {
+                        index: unsaturated.index,
+                        isomerism: unsaturated.isomerism.map(|isomerism| isomerism as _),
+                        unsaturation: unsaturated
+                            .unsaturation
+                            .map(|unsaturation| unsaturation as _),
+                    })
+                    .collect(),
+            }),
+            stereospecific_number123: stereospecific_numbers123.get(index),
+            stereospecific_number2: stereospecific_numbers2.get(index),
+        });
+    }
+    Ok(Document {
+        schema: SCHEMA,
+        meta,
+        data,
+    })
+}
+
+/// Reads the data frame of the document rows
+pub fn from_document(rows: &[Row]) -> Result<DataFrame> {
+    let mut fatty_acids = Vec::with_capacity(rows.len());
+    for row in rows {
+        fatty_acids.push(match &row.fatty_acid {
+            Some(fatty_acid) => {
+                let mut unsaturated = Vec::with_capacity(fatty_acid.unsaturated.len());
+                for row in &fatty_acid.unsaturated {
+                    unsaturated.push(Unsaturated {
+                        index: row.index,
+                        isomerism: match row.isomerism {
+                            None => None,
+                            Some(1) => Some(Isomerism::Cis),
+                            Some(-1) => Some(Isomerism::Trans),
+                            Some(isomerism) => bail!("unexpected isomerism {isomerism}"),
+                        },
+                        unsaturation: match row.unsaturation {
+                            None => None,
+                            Some(1) => Some(Unsaturation::One),
+                            Some(2) => Some(Unsaturation::Two),
+                            Some(unsaturation) => bail!("unexpected unsaturation {unsaturation}"),
+                        },
+                    });
+                }
+                Some(FattyAcid {
+                    carbons: fatty_acid.carbons,
+                    unsaturated,
+                })
+            }
+            None => None,
+        });
+    }
+    Ok(DataFrame::new(vec![
+        fatty_acid_series("FattyAcid".into(), &fatty_acids)?.into_column(),
+        Column::new(
+            "StereospecificNumber123".into(),
+            rows.iter()
+                .map(|row| row.stereospecific_number123)
+                .collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "StereospecificNumber2".into(),
+            rows.iter()
+                .map(|row| row.stereospecific_number2)
+                .collect::<Vec<_>>(),
+        ),
+    ])?)
+}
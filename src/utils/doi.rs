@@ -0,0 +1,27 @@
+/// Finds a DOI in a text, `DOI: 10.1021/jf903048p` or
+/// `https://doi.org/10.1021/jf903048p`
+///
+/// The first `10.<digits>/<suffix>` is taken, other numbers starting with `10.`
+/// (`v10.2`) are skipped.
+pub fn doi(text: &str) -> Option<&str> {
+    text.match_indices("10.").find_map(|(start, _)| {
+        if text[..start].ends_with(|char: char| char.is_ascii_digit() || char == '.') {
+            return None;
+        }
+        let doi = text[start..]
+            .split(|char: char| char.is_whitespace() || matches!(char, '<' | '>' | '"' | ','))
+            .next()?
+            .trim_end_matches(['.', ')', ';']);
+        let (prefix, suffix) = doi.split_once('/')?;
+        let registrant = &prefix[3..];
+        (!registrant.is_empty()
+            && registrant
+                .chars()
+                .all(|char| char.is_ascii_digit() || char == '.')
+            && !suffix.is_empty())
+        .then_some(doi)
+    })
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,35 @@
+use super::*;
+
+#[test]
+fn found() {
+    assert_eq!(doi("DOI: 10.1021/jf903048p"), Some("10.1021/jf903048p"));
+    assert_eq!(
+        doi("https://doi.org/10.1021/jf903048p"),
+        Some("10.1021/jf903048p")
+    );
+    assert_eq!(
+        doi("<https://doi.org/10.1016/j.algal.2018.11.004>"),
+        Some("10.1016/j.algal.2018.11.004"),
+    );
+    assert_eq!(
+        doi("Mature milk fat (10.1021/jf903048p)."),
+        Some("10.1021/jf903048p")
+    );
+}
+
+#[test]
+fn skipped() {
+    assert_eq!(
+        doi("v10.2, DOI: 10.1021/jf903048p"),
+        Some("10.1021/jf903048p")
+    );
+    assert_eq!(
+        doi("10.5 %, 10.1038/sj.ejcn.1601470"),
+        Some("10.1038/sj.ejcn.1601470")
+    );
+    assert_eq!(doi("110.1021/jf903048p"), None);
+    assert_eq!(doi("10./jf903048p"), None);
+    assert_eq!(doi("10.1021/"), None);
+    assert_eq!(doi("10.1021"), None);
+    assert_eq!(doi(""), None);
+}
@@ -0,0 +1,252 @@
+use crate::presets::_10_1021_jf903048p::MATURE_MILK_FAT;
+use anyhow::{Context as _, Result, bail, ensure};
+use lipid::fatty_acid::{
+    FattyAcid, Isomerism, Unsaturated, Unsaturation, polars::DataFrameExt as _,
+};
+use polars::{chunked_array::builder::AnonymousOwnedListBuilder, prelude::*};
+use std::sync::LazyLock;
+
+/// Reference fatty acids
+static REFERENCE: LazyLock<Vec<FattyAcid>> = LazyLock::new(|| {
+    let fatty_acids = MATURE_MILK_FAT.data.fatty_acid();
+    (0..fatty_acids.len())
+        .filter_map(|index| fatty_acids.get(index).ok().flatten())
+        .collect()
+});
+
+/// Parses a fatty acid label
+///
+/// Accepts `16:0`, `C18:1`, `18:1n-9`, `18:2ω6`, `18:1Δ9c`, `18:2 (9Z,12Z)`,
+/// `18:3 cis-9,12,15` and `18:1t`. Double bonds are cis unless stated
+/// otherwise, their indices are unknown without positions or an n-x (ω-x)
+/// notation, see [`resolve_fatty_acid`].
+pub fn parse_fatty_acid(label: &str) -> Result<FattyAcid> {
+    let text = label.trim().to_lowercase();
+    let text = text.strip_prefix('c').unwrap_or(&text);
+    let (carbons, rest) = text
+        .split_once(':')
+        .with_context(|| format!("expected `carbons:bonds`, found `{label}`"))?;
+    let carbons = carbons
+        .trim()
+        .parse::<u8>()
+        .with_context(|| format!("invalid carbons in `{label}`"))?;
+    let rest = rest.trim_start();
+    let end = rest
+        .find(|char: char| !char.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let bonds = rest[..end]
+        .parse::<u8>()
+        .with_context(|| format!("invalid number of double bonds in `{label}`"))?;
+    ensure!(
+        bonds < carbons,
+        "too many double bonds for {carbons} carbons in `{label}`"
+    );
+    // Brackets and spaces are dropped after the bonds, `18:2 (9Z,12Z)` has 2
+    let rest = rest[end..]
+        .chars()
+        .filter(|char| !matches!(char, '(' | ')' | '[' | ']') && !char.is_whitespace())
+        .collect::<String>();
+    let rest = rest.as_str();
+    let mut indices = vec![None; bonds as _];
+    let mut isomerisms = vec![None; bonds as _];
+    if let Some(omega) = rest
+        .strip_prefix('n')
+        .or_else(|| rest.strip_prefix('ω'))
+        .or_else(|| rest.strip_prefix('w'))
+    {
+        // n-x: the last of the methylene interrupted double bonds is x carbons
+        // from the methyl end
+        let omega = omega.strip_prefix('-').unwrap_or(omega);
+        let end = omega
+            .find(|char: char| !char.is_ascii_digit())
+            .unwrap_or(omega.len());
+        let x = omega[..end]
+            .parse::<u8>()
+            .with_context(|| format!("invalid n-x notation in `{label}`"))?;
+        let isomerism = isomerism(&omega[end..], label)?;
+        ensure!(
+            bonds > 0 && x > 0 && carbons as i32 - x as i32 - 3 * (bonds as i32 - 1) > 0,
+            "n-{x} does not fit {carbons}:{bonds} in `{label}`"
+        );
+        let first = carbons - x - 3 * (bonds - 1);
+        for bond in 0..bonds {
+            indices[bond as usize] = Some(first + 3 * bond);
+            isomerisms[bond as usize] = isomerism;
+        }
+    } else {
+        // Δ positions with an optional isomerism, common to all or per bond
+        let (common, rest) = if let Some(rest) = rest.strip_prefix("cis-") {
+            (Some(Isomerism::Cis), rest)
+        } else if let Some(rest) = rest.strip_prefix("trans-") {
+            (Some(Isomerism::Trans), rest)
+        } else {
+            (None, rest)
+        };
+        let rest = rest.strip_prefix('δ').unwrap_or(rest);
+        let rest = rest.strip_prefix('-').unwrap_or(rest);
+        if rest.starts_with(|char: char| char.is_ascii_digit()) {
+            let positions = rest.split([',', '/']).collect::<Vec<_>>();
+            ensure!(
+                positions.len() == bonds as usize,
+                "expected {bonds} double bond positions, found {} in `{label}`",
+                positions.len(),
+            );
+            for (bond, position) in positions.into_iter().enumerate() {
+                let end = position
+                    .find(|char: char| !char.is_ascii_digit())
+                    .unwrap_or(position.len());
+                let index = position[..end]
+                    .parse::<u8>()
+                    .with_context(|| format!("invalid double bond position in `{label}`"))?;
+                ensure!(
+                    0 < index && index < carbons,
+                    "double bond position {index} is beyond {carbons} carbons in `{label}`"
+                );
+                indices[bond] = Some(index);
+                isomerisms[bond] = isomerism(&position[end..], label)?.or(common);
+            }
+        } else {
+            let isomerism = isomerism(rest, label)?.or(common);
+            isomerisms.fill(isomerism);
+        }
+    }
+    let mut unsaturated = indices
+        .into_iter()
+        .zip(isomerisms)
+        .map(|(index, isomerism)| Unsaturated {
+            index,
+            isomerism: Some(isomerism.unwrap_or(Isomerism::Cis)),
+            unsaturation: Some(Unsaturation::One),
+        })
+        .collect::<Vec<_>>();
+    unsaturated.sort_by_key(|unsaturated| unsaturated.index);
+    Ok(FattyAcid {
+        carbons,
+        unsaturated,
+    })
+}
+
+/// Fills unknown double bond indices from the only reference fatty acid with
+/// the same carbons, bonds and isomerism (`18:1` is `18:1Δ9c` in milk fat)
+pub fn resolve_fatty_acid(fatty_acid: FattyAcid) -> FattyAcid {
+    if fatty_acid.unsaturated.is_empty()
+        || fatty_acid
+            .unsaturated
+            .iter()
+            .any(|unsaturated| unsaturated.index.is_some())
+    {
+        return fatty_acid;
+    }
+    let isomerism = |fatty_acid: &FattyAcid| {
+        fatty_acid
+            .unsaturated
+            .iter()
+            .map(|unsaturated| unsaturated.isomerism.map(|isomerism| isomerism as i8))
+            .collect::<Vec<_>>()
+    };
+    let mut candidates = REFERENCE.iter().filter(|candidate| {
+        candidate.carbons == fatty_acid.carbons
+            && candidate.unsaturated.len() == fatty_acid.unsaturated.len()
+            && isomerism(candidate) == isomerism(&fatty_acid)
+    });
+    match (candidates.next(), candidates.next()) {
+        (Some(candidate), None) => candidate.clone(),
+        _ => fatty_acid,
+    }
+}
+
+/// Fatty acid series of the `Carbons`, `Unsaturated` layout
+pub fn fatty_acid_series(
+    name: PlSmallStr,
+    fatty_acids: &[Option<FattyAcid>],
+) -> PolarsResult<Series> {
+    let mut carbons =
+        PrimitiveChunkedBuilder::<UInt8Type>::new("Carbons".into(), fatty_acids.len());
+    let mut unsaturated = AnonymousOwnedListBuilder::new(
+        "Unsaturated".into(),
+        fatty_acids.len(),
+        Some(DataType::Struct(vec![
+            Field::new("Index".into(), DataType::UInt8),
+            Field::new("Isomerism".into(), DataType::Int8),
+            Field::new("Unsaturation".into(), DataType::UInt8),
+        ])),
+    );
+    for fatty_acid in fatty_acids {
+        // Carbons
+        carbons.append_option(fatty_acid.as_ref().map(|fatty_acid| fatty_acid.carbons));
+        // Unsaturated
+        if let Some(fatty_acid) = fatty_acid {
+            let mut index = PrimitiveChunkedBuilder::<UInt8Type>::new(
+                "Index".into(),
+                fatty_acid.unsaturated.len(),
+            );
+            let mut isomerism = PrimitiveChunkedBuilder::<Int8Type>::new(
+                "Isomerism".into(),
+                fatty_acid.unsaturated.len(),
+            );
+            let mut unsaturation = PrimitiveChunkedBuilder::<UInt8Type>::new(
+                "Unsaturation".into(),
+                fatty_acid.unsaturated.len(),
+            );
+            for unsaturated in &fatty_acid.unsaturated {
+                index.append_option(unsaturated.index);
+                isomerism.append_option(unsaturated.isomerism.map(|isomerism| isomerism as _));
+                unsaturation.append_option(
+                    unsaturated
+                        .unsaturation
+                        .map(|unsaturation| unsaturation as _),
+                );
+            }
+            unsaturated.append_series(
+                &StructChunked::from_series(
+                    PlSmallStr::EMPTY,
+                    fatty_acid.unsaturated.len(),
+                    [
+                        index.finish().into_series(),
+                        isomerism.finish().into_series(),
+                        unsaturation.finish().into_series(),
+                    ]
+                    .iter(),
+                )?
+                .into_series(),
+            )?;
+        } else {
+            unsaturated.append_opt_series(None)?;
+        }
+    }
+    Ok(StructChunked::from_series(
+        name,
+        fatty_acids.len(),
+        [
+            carbons.finish().into_series(),
+            unsaturated.finish().into_series(),
+        ]
+        .iter(),
+    )?
+    .into_series())
+}
+
+fn isomerism(text: &str, label: &str) -> Result<Option<Isomerism>> {
+    Ok(match text {
+        "" => None,
+        "c" | "z" | "cis" => Some(Isomerism::Cis),
+        "t" | "e" | "trans" => Some(Isomerism::Trans),
+        _ => bail!("unexpected `{text}` in `{label}`"),
+    })
+}
+
+/// Data frame of a calculation pane
+pub fn sample_data_frame(
+    fatty_acids: &[Option<FattyAcid>],
+    stereospecific_numbers123: Vec<f64>,
+    stereospecific_numbers2: Vec<f64>,
+) -> PolarsResult<DataFrame> {
+    DataFrame::new(vec![
+        fatty_acid_series("FattyAcid".into(), fatty_acids)?.into_column(),
+        Column::new("StereospecificNumber123".into(), stereospecific_numbers123),
+        Column::new("StereospecificNumber2".into(), stereospecific_numbers2),
+    ])
+}
+
+#[cfg(test)]
+mod test;
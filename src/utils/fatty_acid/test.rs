@@ -0,0 +1,137 @@
+use super::*;
+
+const C: Option<i8> = Some(Isomerism::Cis as _);
+const T: Option<i8> = Some(Isomerism::Trans as _);
+
+/// Carbons and the index and isomerism of each double bond
+fn bonds(fatty_acid: &FattyAcid) -> (u8, Vec<(Option<u8>, Option<i8>)>) {
+    for unsaturated in &fatty_acid.unsaturated {
+        assert!(matches!(unsaturated.unsaturation, Some(Unsaturation::One)));
+    }
+    (
+        fatty_acid.carbons,
+        fatty_acid
+            .unsaturated
+            .iter()
+            .map(|unsaturated| {
+                (
+                    unsaturated.index,
+                    unsaturated.isomerism.map(|isomerism| isomerism as _),
+                )
+            })
+            .collect(),
+    )
+}
+
+fn parse(label: &str) -> (u8, Vec<(Option<u8>, Option<i8>)>) {
+    bonds(&parse_fatty_acid(label).unwrap_or_else(|error| panic!("{label}: {error}")))
+}
+
+#[test]
+fn saturated() {
+    assert_eq!(parse("16:0"), (16, vec![]));
+    assert_eq!(parse("C18:0"), (18, vec![]));
+    assert_eq!(parse(" c12:0 "), (12, vec![]));
+}
+
+#[test]
+fn unknown_indices() {
+    assert_eq!(parse("C18:1"), (18, vec![(None, C)]));
+    assert_eq!(parse("18:2"), (18, vec![(None, C), (None, C)]));
+    assert_eq!(parse("18:1t"), (18, vec![(None, T)]));
+}
+
+#[test]
+fn omega() {
+    assert_eq!(parse("18:1n-9"), (18, vec![(Some(9), C)]));
+    assert_eq!(parse("18:2ω6"), (18, vec![(Some(9), C), (Some(12), C)]));
+    assert_eq!(parse("18:2n-6"), (18, vec![(Some(9), C), (Some(12), C)]));
+    assert_eq!(
+        parse("18:3n-3"),
+        (18, vec![(Some(9), C), (Some(12), C), (Some(15), C)]),
+    );
+    assert_eq!(
+        parse("18:3ω-6"),
+        (18, vec![(Some(6), C), (Some(9), C), (Some(12), C)]),
+    );
+    assert_eq!(
+        parse("20:4n-6"),
+        (
+            20,
+            vec![(Some(5), C), (Some(8), C), (Some(11), C), (Some(14), C)],
+        ),
+    );
+    assert_eq!(
+        parse("22:6n-3"),
+        (
+            22,
+            [4, 7, 10, 13, 16, 19]
+                .into_iter()
+                .map(|index| (Some(index), C))
+                .collect(),
+        ),
+    );
+    assert_eq!(parse("18:1w9t"), (18, vec![(Some(9), T)]));
+    assert!(parse_fatty_acid("18:2n-17").is_err());
+    assert!(parse_fatty_acid("18:3n-16").is_err());
+    assert!(parse_fatty_acid("18:1n-0").is_err());
+    assert!(parse_fatty_acid("18:0n-9").is_err());
+}
+
+#[test]
+fn delta() {
+    assert_eq!(parse("18:1Δ9c"), (18, vec![(Some(9), C)]));
+    assert_eq!(parse("18:1Δ9t"), (18, vec![(Some(9), T)]));
+    assert_eq!(parse("18:1Δ-11"), (18, vec![(Some(11), C)]));
+    assert_eq!(
+        parse("18:2 (9Z,12Z)"),
+        (18, vec![(Some(9), C), (Some(12), C)]),
+    );
+    assert_eq!(
+        parse("18:2 (9Z,11E)"),
+        (18, vec![(Some(9), C), (Some(11), T)]),
+    );
+    assert_eq!(
+        parse("18:3 cis-9,12,15"),
+        (18, vec![(Some(9), C), (Some(12), C), (Some(15), C)]),
+    );
+    assert_eq!(
+        parse("18:2 trans-9/12"),
+        (18, vec![(Some(9), T), (Some(12), T)]),
+    );
+    // Sorted by the index
+    assert_eq!(parse("18:2Δ12,9"), (18, vec![(Some(9), C), (Some(12), C)]));
+}
+
+#[test]
+fn invalid() {
+    for label in [
+        "",
+        "18",
+        "x:1",
+        "18:x",
+        "18:18",
+        "18:2Δ9",
+        "18:1Δ9,12",
+        "18:1Δ0",
+        "18:1Δ18",
+        "18:1Δ9x",
+        "18:1q",
+    ] {
+        assert!(parse_fatty_acid(label).is_err(), "{label}");
+    }
+}
+
+#[test]
+fn resolve() {
+    let resolve = |label| bonds(&resolve_fatty_acid(parse_fatty_acid(label).unwrap()));
+    assert_eq!(resolve("18:1"), (18, vec![(Some(9), C)]));
+    assert_eq!(resolve("18:2"), (18, vec![(Some(9), C), (Some(12), C)]));
+    // Saturated and indexed fatty acids are kept
+    assert_eq!(resolve("16:0"), (16, vec![]));
+    assert_eq!(resolve("18:1Δ11"), (18, vec![(Some(11), C)]));
+    // Ambiguous: 18:3Δ6,9,12 and 18:3Δ9,12,15
+    assert_eq!(resolve("18:3"), (18, vec![(None, C), (None, C), (None, C)]));
+    // Not in the reference
+    assert_eq!(resolve("18:1t"), (18, vec![(None, T)]));
+}
@@ -0,0 +1,17 @@
+use polars::prelude::*;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// Content hash of a data frame
+///
+/// Walks every value, so it should be computed once per edit and not per
+/// frame.
+pub fn hash_data_frame(data_frame: &DataFrame) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for series in data_frame.iter() {
+        series.name().hash(&mut hasher);
+        for value in series.iter() {
+            value.hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
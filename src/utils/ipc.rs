@@ -0,0 +1,38 @@
+use anyhow::Result;
+use metadata::MetaDataFrame;
+use polars::prelude::*;
+use std::{io::Cursor, sync::Arc};
+
+/// Writes the frame as IPC with the schema version
+///
+/// The metadata of the frame is encoded by [`MetaDataFrame`] into the custom
+/// schema metadata of the empty data, the `schema` key is added next to it and
+/// the data is written once.
+pub fn write_ipc(frame: &mut MetaDataFrame, schema: u32) -> Result<Vec<u8>> {
+    let mut header = Vec::new();
+    MetaDataFrame::new(frame.meta.clone(), &mut frame.data.clear()).write(&mut header)?;
+    let mut metadata = IpcReader::new(Cursor::new(&header))
+        .custom_metadata()?
+        .map(Arc::unwrap_or_clone)
+        .unwrap_or_default();
+    metadata.insert("schema".into(), schema.to_string().into());
+    let mut bytes = Vec::new();
+    let mut writer = IpcWriter::new(&mut bytes);
+    writer.set_custom_schema_metadata(Arc::new(metadata));
+    writer.finish(&mut frame.data)?;
+    Ok(bytes)
+}
+
+/// Reads the schema version of IPC, none if it was written before versioning
+pub fn read_ipc_schema(bytes: &[u8]) -> Result<Option<u32>> {
+    let metadata = IpcReader::new(Cursor::new(bytes)).custom_metadata()?;
+    Ok(
+        match metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("schema"))
+        {
+            Some(schema) => Some(schema.parse()?),
+            None => None,
+        },
+    )
+}
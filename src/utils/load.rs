@@ -0,0 +1,157 @@
+use super::{Document, check_schema, from_document, migrate, read_columns, read_ipc_schema};
+#[cfg(not(target_arch = "wasm32"))]
+use super::{doi, read_parquet};
+use anyhow::{Context as _, Result};
+use metadata::MetaDataFrame;
+use std::{io::Cursor, str};
+
+/// Format of a saved frame
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Ipc,
+    Ron,
+    Json,
+    /// Not available on the web, its compression codecs are native
+    #[cfg(not(target_arch = "wasm32"))]
+    Parquet,
+}
+
+impl Format {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub const ALL: [Self; 4] = [Self::Ipc, Self::Ron, Self::Json, Self::Parquet];
+    #[cfg(target_arch = "wasm32")]
+    pub const ALL: [Self; 3] = [Self::Ipc, Self::Ron, Self::Json];
+
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            Self::Ipc => "ipc",
+            Self::Ron => "ron",
+            Self::Json => "json",
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::Parquet => "parquet",
+        }
+    }
+
+    pub const fn text(&self) -> &'static str {
+        match self {
+            Self::Ipc => "IPC",
+            Self::Ron => "RON",
+            Self::Json => "JSON",
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::Parquet => "Parquet",
+        }
+    }
+
+    /// Detects the format by the magic bytes, then by the extension
+    pub fn detect(name: &str, bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(b"ARROW1") {
+            return Some(Self::Ipc);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if bytes.starts_with(b"PAR1") {
+            return Some(Self::Parquet);
+        }
+        if name.ends_with(".ipc") || name.ends_with(".arrow") {
+            return Some(Self::Ipc);
+        }
+        if name.ends_with(".ron") {
+            return Some(Self::Ron);
+        }
+        if name.ends_with(".json") {
+            return Some(Self::Json);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if name.ends_with(".parquet") {
+            return Some(Self::Parquet);
+        }
+        // RON starts with its extensions or a struct, JSON with an object
+        let text = str::from_utf8(bytes).ok()?.trim_start();
+        if text.starts_with("#![enable") || text.starts_with('(') {
+            Some(Self::Ron)
+        } else if text.starts_with('{') {
+            Some(Self::Json)
+        } else {
+            None
+        }
+    }
+}
+
+/// Reads a frame saved as IPC, RON, JSON or Parquet
+///
+/// RON and JSON hold a [`Document`], older RON files may hold only the data
+/// in the column layout of polars. IPC holds the schema version next to the
+/// metadata. Older layouts are upgraded by [`migrate`],
+/// files of a newer schema are rejected.
+/// Parquet holds the metadata in its key-value metadata, the input of
+/// calculated results (`Experimental`) is read back as the data.
+/// The name of the file without its extensions is used if the frame has no
+/// name.
+pub fn load(name: &str, bytes: &[u8]) -> Result<MetaDataFrame> {
+    let format = Format::detect(name, bytes).context("unknown format")?;
+    let mut frame: MetaDataFrame = match format {
+        Format::Ipc => {
+            if let Some(schema) = read_ipc_schema(bytes)? {
+                check_schema(schema)?;
+            }
+            let mut frame: MetaDataFrame = MetaDataFrame::read(Cursor::new(bytes))?;
+            frame.data = migrate(frame.data)?;
+            frame
+        }
+        Format::Ron => {
+            let text = str::from_utf8(bytes)?;
+            match ron::from_str::<Document<_>>(text) {
+                Ok(document) => {
+                    check_schema(document.schema)?;
+                    MetaDataFrame::new(document.meta, from_document(&document.data)?)
+                }
+                Err(error) => match read_columns(text) {
+                    Ok(data) => MetaDataFrame::new(Default::default(), data),
+                    Err(_) => return Err(error.into()),
+                },
+            }
+        }
+        Format::Json => {
+            let document = serde_json::from_slice::<Document<_>>(bytes)?;
+            check_schema(document.schema)?;
+            MetaDataFrame::new(document.meta, from_document(&document.data)?)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        Format::Parquet => {
+            let (mut data, metadata) = read_parquet(bytes)?;
+            if data.get_column_index("Experimental").is_some() {
+                data = data
+                    .select(["FattyAcid", "Experimental"])?
+                    .unnest(["Experimental"])?;
+            }
+            let mut frame = MetaDataFrame::new(Default::default(), migrate(data)?);
+            for (key, value) in metadata {
+                match &*key {
+                    "schema" => check_schema(value.parse()?)?,
+                    "name" => frame.meta.name = value,
+                    "version" => frame.meta.version = Some(value.parse()?),
+                    "description" => frame.meta.description = value,
+                    "doi" if doi(&frame.meta.description).is_none() => {
+                        frame.meta.description = format!("DOI: {value}");
+                    }
+                    _ => {}
+                }
+            }
+            frame
+        }
+    };
+    if frame.meta.name.is_empty() {
+        frame.meta.name = stem(name).to_owned();
+    }
+    Ok(frame)
+}
+
+/// File name without the `.hmf`, `.ipc`, `.ron`, `.json` and `.parquet` extensions
+fn stem(name: &str) -> &str {
+    let name = name
+        .strip_suffix(".ipc")
+        .or_else(|| name.strip_suffix(".ron"))
+        .or_else(|| name.strip_suffix(".json"))
+        .or_else(|| name.strip_suffix(".parquet"))
+        .unwrap_or(name);
+    name.strip_suffix(".hmf").unwrap_or(name)
+}
@@ -0,0 +1,209 @@
+use polars::prelude::*;
+
+/// Markup of a table
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Markup {
+    AsciiDoc,
+    Markdown,
+}
+
+impl Markup {
+    pub const ALL: [Self; 2] = [Self::AsciiDoc, Self::Markdown];
+
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            Self::AsciiDoc => "adoc",
+            Self::Markdown => "md",
+        }
+    }
+
+    pub const fn text(&self) -> &'static str {
+        match self {
+            Self::AsciiDoc => "AsciiDoc",
+            Self::Markdown => "Markdown",
+        }
+    }
+}
+
+/// Table with a grouped header
+///
+/// Groups span their columns, the names of the columns follow in the second
+/// header row.
+#[derive(Clone, Debug, Default)]
+pub struct Table {
+    /// Text and number of columns of the groups
+    pub groups: Vec<(String, usize)>,
+    pub names: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Rows of the data frame, floats with the precision
+    pub fn rows(data_frame: &DataFrame, precision: usize) -> PolarsResult<Vec<Vec<String>>> {
+        let mut rows = Vec::with_capacity(data_frame.height());
+        for index in 0..data_frame.height() {
+            let mut row = Vec::with_capacity(data_frame.width());
+            for column in data_frame.get_columns() {
+                row.push(match column.get(index)? {
+                    AnyValue::Null => String::new(),
+                    AnyValue::Float64(value) => format!("{value:.precision$}"),
+                    AnyValue::Float32(value) => format!("{value:.precision$}"),
+                    AnyValue::String(value) => value.to_owned(),
+                    AnyValue::StringOwned(value) => value.to_string(),
+                    value => value.to_string(),
+                });
+            }
+            rows.push(row);
+        }
+        Ok(rows)
+    }
+
+    pub fn format(&self, markup: Markup) -> String {
+        match markup {
+            Markup::AsciiDoc => self.asciidoc(),
+            Markup::Markdown => self.markdown(),
+        }
+    }
+
+    /// AsciiDoc table, the groups and the names are the header rows followed
+    /// by a blank line
+    pub fn asciidoc(&self) -> String {
+        let escape = |text: &str| text.replace('|', "\\|");
+        let widths = self.widths(escape);
+        let mut text = String::from("|===\n");
+        for (group, span) in &self.groups {
+            if *span > 1 {
+                text.push_str(&format!("{span}+"));
+            }
+            text.push('|');
+            text.push_str(&escape(group));
+            text.push(' ');
+        }
+        text.truncate(text.trim_end().len());
+        text.push('\n');
+        text.push_str(&line(&self.names, &widths, escape, "|", ""));
+        text.push('\n');
+        for row in &self.rows {
+            text.push_str(&line(row, &widths, escape, "|", ""));
+        }
+        text.push_str("|===\n");
+        text
+    }
+
+    /// GitHub Markdown table, the groups are the header row and the names the
+    /// first row in bold
+    pub fn markdown(&self) -> String {
+        let escape = |text: &str| text.replace('|', "\\|");
+        let names = self
+            .names
+            .iter()
+            .map(|name| format!("**{}**", escape(name)))
+            .collect::<Vec<_>>();
+        let mut groups = Vec::with_capacity(self.names.len());
+        for (group, span) in &self.groups {
+            groups.push(escape(group));
+            groups.extend((1..*span).map(|_| String::new()));
+        }
+        let mut widths = self.widths(escape);
+        for (index, width) in widths.iter_mut().enumerate() {
+            for text in names.get(index).into_iter().chain(groups.get(index)) {
+                *width = (*width).max(text.chars().count()).max(3);
+            }
+        }
+        let mut text = line(&groups, &widths, String::from, "| ", "|");
+        let rule = widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>();
+        text.push_str(&line(&rule, &widths, String::from, "| ", "|"));
+        text.push_str(&line(&names, &widths, String::from, "| ", "|"));
+        for row in &self.rows {
+            text.push_str(&line(row, &widths, escape, "| ", "|"));
+        }
+        text
+    }
+
+    /// HTML table, the groups span their columns
+    pub fn html(&self) -> String {
+        let mut text = String::from("<table>\n<thead>\n<tr>");
+        for (group, span) in &self.groups {
+            if *span > 1 {
+                text.push_str(&format!(
+                    "<th colspan=\"{span}\">{}</th>",
+                    escape_html(group)
+                ));
+            } else {
+                text.push_str(&format!("<th>{}</th>", escape_html(group)));
+            }
+        }
+        text.push_str("</tr>\n<tr>");
+        for name in &self.names {
+            text.push_str(&format!("<th>{}</th>", escape_html(name)));
+        }
+        text.push_str("</tr>\n</thead>\n<tbody>\n");
+        for row in &self.rows {
+            text.push_str("<tr>");
+            for cell in row {
+                text.push_str(&format!("<td>{}</td>", escape_html(cell)));
+            }
+            text.push_str("</tr>\n");
+        }
+        text.push_str("</tbody>\n</table>\n");
+        text
+    }
+
+    /// Widths of the escaped columns
+    fn widths(&self, escape: impl Fn(&str) -> String) -> Vec<usize> {
+        let mut widths = vec![0; self.names.len()];
+        for row in [&self.names].into_iter().chain(&self.rows) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(escape(cell).chars().count());
+            }
+        }
+        widths
+    }
+}
+
+/// Line of cells padded to the widths
+fn line<T: AsRef<str>>(
+    cells: &[T],
+    widths: &[usize],
+    escape: impl Fn(&str) -> String,
+    separator: &str,
+    end: &str,
+) -> String {
+    let mut text = String::new();
+    for (index, width) in widths.iter().enumerate() {
+        let cell = cells
+            .get(index)
+            .map_or(String::new(), |cell| escape(cell.as_ref()));
+        text.push_str(separator);
+        text.push_str(&format!("{cell:width$}"));
+        if !end.is_empty() {
+            text.push(' ');
+        }
+    }
+    text.push_str(end);
+    text.truncate(text.trim_end().len());
+    text.push('\n');
+    text
+}
+
+/// Escapes `&`, `<`, `>` and quotes
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,58 @@
+use super::*;
+
+fn table() -> Table {
+    Table {
+        groups: vec![("ID".to_owned(), 2), ("A|B".to_owned(), 1)],
+        names: ["#", "FA", "Value"].map(ToOwned::to_owned).into(),
+        rows: vec![
+            ["1", "16:0", "25.00"].map(ToOwned::to_owned).into(),
+            ["2", "x|y", "3.50"].map(ToOwned::to_owned).into(),
+        ],
+    }
+}
+
+#[test]
+fn asciidoc() {
+    assert_eq!(
+        table().format(Markup::AsciiDoc),
+        concat!(
+            "|===\n",
+            "2+|ID |A\\|B\n",
+            "|#|FA  |Value\n",
+            "\n",
+            "|1|16:0|25.00\n",
+            "|2|x\\|y|3.50\n",
+            "|===\n",
+        ),
+    );
+}
+
+#[test]
+fn markdown() {
+    assert_eq!(
+        table().format(Markup::Markdown),
+        concat!(
+            "| ID    |        | A\\|B      |\n",
+            "| ----- | ------ | --------- |\n",
+            "| **#** | **FA** | **Value** |\n",
+            "| 1     | 16:0   | 25.00     |\n",
+            "| 2     | x\\|y   | 3.50      |\n",
+        ),
+    );
+}
+
+#[test]
+fn html() {
+    assert_eq!(
+        table().html(),
+        concat!(
+            "<table>\n<thead>\n",
+            "<tr><th colspan=\"2\">ID</th><th>A|B</th></tr>\n",
+            "<tr><th>#</th><th>FA</th><th>Value</th></tr>\n",
+            "</thead>\n<tbody>\n",
+            "<tr><td>1</td><td>16:0</td><td>25.00</td></tr>\n",
+            "<tr><td>2</td><td>x|y</td><td>3.50</td></tr>\n",
+            "</tbody>\n</table>\n",
+        ),
+    );
+}
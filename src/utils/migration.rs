@@ -0,0 +1,190 @@
+use super::fatty_acid_series;
+use anyhow::{Result, bail, ensure};
+use lipid::fatty_acid::{FattyAcid, Isomerism, Unsaturated, Unsaturation};
+use polars::{chunked_array::builder::AnonymousOwnedListBuilder, prelude::*};
+use serde::Deserialize;
+
+/// Schema version of saved files
+///
+/// Documents (RON, JSON) hold it in `Schema`, Parquet in its key-value
+/// metadata and IPC in its custom schema metadata, both under `schema`. Files
+/// without it were written before versioning, older layouts are upgraded by
+/// [`migrate`].
+pub const SCHEMA: u32 = 1;
+
+/// Fails on files written by a newer version of the application
+pub fn check_schema(schema: u32) -> Result<()> {
+    ensure!(
+        schema <= SCHEMA,
+        "schema version {schema} is newer than the supported {SCHEMA}"
+    );
+    Ok(())
+}
+
+/// Upgrades older layouts of the data frame to the current one
+///
+/// - `TAG` and `MAG` are renamed to `StereospecificNumber123` and
+///   `StereospecificNumber2`,
+/// - fatty acids with `Indices` and `Bounds` are converted to `Unsaturated`.
+pub fn migrate(mut data_frame: DataFrame) -> Result<DataFrame> {
+    for (old, new) in [
+        ("TAG", "StereospecificNumber123"),
+        ("MAG", "StereospecificNumber2"),
+    ] {
+        if data_frame.get_column_index(old).is_some() && data_frame.get_column_index(new).is_none()
+        {
+            data_frame.rename(old, new.into())?;
+        }
+    }
+    let legacy = match data_frame.column("FattyAcid").map(Column::dtype) {
+        Ok(DataType::Struct(fields)) => fields.iter().any(|field| field.name == "Indices"),
+        _ => false,
+    };
+    if legacy {
+        let fatty_acids = fatty_acids(data_frame["FattyAcid"].as_materialized_series())?;
+        data_frame.with_column(fatty_acids)?;
+    }
+    Ok(data_frame)
+}
+
+/// Reads a data frame in the column layout of older RON files
+///
+/// Older versions saved the data frame by the serde implementation of polars:
+/// `(columns: [{"name": …, "datatype": …, "values": […]}, …])`. Struct
+/// columns hold their fields in `values`, list columns one unnamed series per
+/// row. The data types of the known columns are restored by their names.
+pub fn read_columns(text: &str) -> Result<DataFrame> {
+    let value = ron::from_str::<ron::Value>(text)?;
+    let frame = value.into_rust::<LegacyFrame>()?;
+    let mut columns = Vec::with_capacity(frame.columns.len());
+    for column in frame.columns {
+        columns.push(series(column, "")?.into_column());
+    }
+    migrate(DataFrame::new(columns)?)
+}
+
+#[derive(Deserialize)]
+struct LegacyFrame {
+    columns: Vec<LegacySeries>,
+}
+
+#[derive(Deserialize)]
+struct LegacySeries {
+    name: String,
+    values: LegacyValues,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LegacyValues {
+    Series(Vec<LegacySeries>),
+    Numbers(Vec<Option<f64>>),
+}
+
+fn series(legacy: LegacySeries, parent: &str) -> Result<Series> {
+    // List rows are unnamed, their type is known by the list name
+    let hint = if legacy.name.is_empty() {
+        parent
+    } else {
+        &legacy.name
+    };
+    let name = PlSmallStr::from(&*legacy.name);
+    Ok(match legacy.values {
+        LegacyValues::Numbers(numbers) => Series::new(name, numbers).cast(&data_type(hint))?,
+        LegacyValues::Series(values) if values.is_empty() => {
+            Series::new_empty(name, &data_type(hint))
+        }
+        LegacyValues::Series(values) if values.iter().all(|value| value.name.is_empty()) => {
+            let rows = values
+                .into_iter()
+                .map(|value| series(value, hint))
+                .collect::<Result<Vec<_>>>()?;
+            let mut builder =
+                AnonymousOwnedListBuilder::new(name, rows.len(), Some(rows[0].dtype().clone()));
+            for row in &rows {
+                builder.append_series(row)?;
+            }
+            builder.finish().into_series()
+        }
+        LegacyValues::Series(values) => {
+            let fields = values
+                .into_iter()
+                .map(|value| series(value, hint))
+                .collect::<Result<Vec<_>>>()?;
+            StructChunked::from_series(name, fields[0].len(), fields.iter())?.into_series()
+        }
+    })
+}
+
+fn data_type(name: &str) -> DataType {
+    match name {
+        "Carbons" | "Index" | "Unsaturation" | "Bounds" => DataType::UInt8,
+        "Isomerism" | "Indices" => DataType::Int8,
+        _ => DataType::Float64,
+    }
+}
+
+/// Converts fatty acids with `Carbons`, `Indices` and `Bounds`
+fn fatty_acids(series: &Series) -> Result<Series> {
+    let fields = series.struct_()?;
+    let carbons = fields.field_by_name("Carbons")?.cast(&DataType::UInt8)?;
+    let indices = fields.field_by_name("Indices")?;
+    let bounds = fields.field_by_name("Bounds")?;
+    let mut fatty_acids = Vec::with_capacity(series.len());
+    for ((carbons, indices), bounds) in carbons
+        .u8()?
+        .iter()
+        .zip(indices.list()?)
+        .zip(bounds.list()?)
+    {
+        fatty_acids.push(match carbons {
+            Some(carbons) => {
+                let indices: Vec<_> = match indices {
+                    Some(indices) => indices.cast(&DataType::Int8)?.i8()?.iter().collect(),
+                    None => Vec::new(),
+                };
+                let bounds: Vec<_> = match bounds {
+                    Some(bounds) => bounds.cast(&DataType::UInt8)?.u8()?.iter().collect(),
+                    None => Vec::new(),
+                };
+                Some(fatty_acid(carbons, &indices, &bounds)?)
+            }
+            None => None,
+        });
+    }
+    Ok(fatty_acid_series(series.name().clone(), &fatty_acids)?)
+}
+
+/// Indices are signed, negative for trans bonds. Bounds are bond orders: 2 for
+/// double and 3 for triple bonds.
+fn fatty_acid(carbons: u8, indices: &[Option<i8>], bounds: &[Option<u8>]) -> Result<FattyAcid> {
+    ensure!(
+        indices.len() == bounds.len(),
+        "{} indices and {} bounds of a fatty acid",
+        indices.len(),
+        bounds.len(),
+    );
+    let mut unsaturated = Vec::with_capacity(indices.len());
+    for (&index, &bound) in indices.iter().zip(bounds) {
+        unsaturated.push(Unsaturated {
+            index: index.map(i8::unsigned_abs),
+            isomerism: Some(match index {
+                Some(index) if index < 0 => Isomerism::Trans,
+                _ => Isomerism::Cis,
+            }),
+            unsaturation: match bound {
+                None => None,
+                Some(2) => Some(Unsaturation::One),
+                Some(3) => Some(Unsaturation::Two),
+                Some(bound) => bail!("unexpected bound {bound}"),
+            },
+        });
+    }
+    Ok(FattyAcid {
+        carbons,
+        unsaturated,
+    })
+}
+
+#[cfg(test)]
+mod test;
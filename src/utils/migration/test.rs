@@ -0,0 +1,209 @@
+use super::*;
+use crate::utils::{load, read_ipc_schema, to_document, write_ipc};
+use lipid::fatty_acid::polars::DataFrameExt as _;
+
+macro trash($name:literal) {
+    (
+        $name,
+        &include_bytes!(concat!("../../presets/trash/", $name))[..],
+    )
+}
+
+const RON: &[(&str, &[u8])] = &[
+    trash!("CP-9.hmf.ron"),
+    trash!("CV-15.hmf.ron"),
+    trash!("CV-395.hmf.ron"),
+    trash!("CZ-30412.hmf.ron"),
+    trash!("H-242_-N 1.hmf.ron"),
+    trash!("H-242_-N 2.hmf.ron"),
+    trash!("H-242_-N 3.hmf.ron"),
+    trash!("HMF_1.hmf.ron"),
+    trash!("HMF_2.hmf.ron"),
+    trash!("HMF_3.hmf.ron"),
+    trash!("HMF_4.hmf.ron"),
+    trash!("ISO-FJ.hmf.ron"),
+];
+
+const IPC: &[(&str, &[u8])] = &[
+    trash!("CV-15.hmf.ipc"),
+    trash!("H242_-N.0.0.1.hmf.ipc"),
+    trash!("H242_-N.0.0.2.hmf.ipc"),
+    trash!("H242_-N.0.0.3.hmf.ipc"),
+];
+
+/// Current layout of a sample: fatty acids, sn-1,2,3 and sn-2
+fn assert_sample(data_frame: &DataFrame) -> Result<()> {
+    assert_eq!(
+        data_frame.get_column_names(),
+        [
+            "FattyAcid",
+            "StereospecificNumber123",
+            "StereospecificNumber2"
+        ],
+    );
+    assert_eq!(
+        data_frame["FattyAcid"].dtype(),
+        fatty_acid_series("FattyAcid".into(), &[])?.dtype(),
+    );
+    assert_eq!(
+        data_frame["StereospecificNumber123"].dtype(),
+        &DataType::Float64
+    );
+    assert_eq!(
+        data_frame["StereospecificNumber2"].dtype(),
+        &DataType::Float64
+    );
+    let fatty_acids = data_frame.fatty_acid();
+    for index in 0..data_frame.height() {
+        assert!(fatty_acids.get(index)?.is_some());
+    }
+    Ok(())
+}
+
+/// Carbons and the index, isomerism and unsaturation of each bond
+fn bonds(fatty_acid: Option<FattyAcid>) -> Option<(u8, Vec<(Option<u8>, Option<i8>, Option<u8>)>)> {
+    fatty_acid.map(|fatty_acid| {
+        let bonds = fatty_acid
+            .unsaturated
+            .iter()
+            .map(|unsaturated| {
+                (
+                    unsaturated.index,
+                    unsaturated.isomerism.map(|isomerism| isomerism as _),
+                    unsaturated
+                        .unsaturation
+                        .map(|unsaturation| unsaturation as _),
+                )
+            })
+            .collect();
+        (fatty_acid.carbons, bonds)
+    })
+}
+
+#[test]
+fn ron_columns() -> Result<()> {
+    for &(name, bytes) in RON {
+        let frame = load(name, bytes)?;
+        assert_eq!(frame.meta.name, name.trim_end_matches(".hmf.ron"));
+        assert!(frame.data.height() > 0, "{name}");
+        assert_sample(&frame.data)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn ron_values() -> Result<()> {
+    let frame = load(RON[1].0, RON[1].1)?;
+    let fatty_acids = frame.data.fatty_acid();
+    assert_eq!(
+        bonds(fatty_acids.get(1)?),
+        Some((18, vec![(Some(9), Some(1), Some(1))]))
+    );
+    let stereospecific_numbers123 = frame.data["StereospecificNumber123"].f64()?;
+    let stereospecific_numbers2 = frame.data["StereospecificNumber2"].f64()?;
+    assert_eq!(stereospecific_numbers123.get(0), Some(21.0));
+    assert_eq!(stereospecific_numbers2.get(0), Some(3.8));
+    Ok(())
+}
+
+#[test]
+fn ipc_columns() -> Result<()> {
+    for &(name, bytes) in IPC {
+        let frame = load(name, bytes)?;
+        assert!(frame.data.height() > 0, "{name}");
+        assert_sample(&frame.data)?;
+    }
+    Ok(())
+}
+
+/// Reference statistics are kept as they are
+#[test]
+fn reference() -> Result<()> {
+    let frame = load(
+        "MatureMilk.ron",
+        include_bytes!("../../presets/trash/mature_milk/MatureMilk.ron"),
+    )?;
+    assert_eq!(frame.data.height(), 33);
+    for name in ["StereospecificNumber123", "StereospecificNumber2"] {
+        assert!(
+            frame.data[name].struct_()?.field_by_name("Median").is_ok(),
+            "{name}",
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn indices_and_bounds() -> Result<()> {
+    let frame = load(
+        "MatureMilk.ron",
+        include_bytes!("../../../doc/10.1021/jf903048p/MatureMilk.ron"),
+    )?;
+    assert_eq!(frame.data.height(), 33);
+    let fatty_acids = frame.data.fatty_acid();
+    assert_eq!(bonds(fatty_acids.get(0)?), Some((10, vec![])));
+    assert_eq!(
+        bonds(fatty_acids.get(4)?),
+        Some((15, vec![(None, Some(1), Some(1))]))
+    );
+    assert_eq!(
+        bonds(fatty_acids.get(12)?),
+        Some((
+            18,
+            vec![(Some(9), Some(1), Some(1)), (Some(12), Some(1), Some(1))]
+        )),
+    );
+    Ok(())
+}
+
+#[test]
+fn signed_indices() -> Result<()> {
+    let legacy = fatty_acid(18, &[Some(-9), Some(12)], &[Some(2), Some(3)])?;
+    assert_eq!(
+        bonds(Some(legacy)),
+        Some((
+            18,
+            vec![(Some(9), Some(-1), Some(1)), (Some(12), Some(1), Some(2))]
+        )),
+    );
+    assert!(fatty_acid(18, &[Some(9)], &[]).is_err());
+    assert!(fatty_acid(18, &[Some(9)], &[Some(4)]).is_err());
+    Ok(())
+}
+
+#[test]
+fn document_schema() -> Result<()> {
+    let frame = load(RON[1].0, RON[1].1)?;
+    let mut document = to_document(&frame.meta, &frame.data)?;
+    assert_eq!(document.schema, SCHEMA);
+    let text = ron::to_string(&document)?;
+    assert!(
+        load("CV-15.hmf.ron", text.as_bytes())?
+            .data
+            .equals_missing(&frame.data)
+    );
+    // Unversioned documents have the layout of the first version
+    let mut value = serde_json::to_value(&document)?;
+    value.as_object_mut().unwrap().remove("Schema");
+    let text = serde_json::to_string(&value)?;
+    assert!(load("CV-15.hmf.json", text.as_bytes()).is_ok());
+    document.schema = SCHEMA + 1;
+    let text = serde_json::to_string(&document)?;
+    assert!(load("CV-15.hmf.json", text.as_bytes()).is_err());
+    Ok(())
+}
+
+#[test]
+fn ipc_schema() -> Result<()> {
+    let mut frame = load(IPC[0].0, IPC[0].1)?;
+    // Written before versioning
+    assert_eq!(read_ipc_schema(IPC[0].1)?, None);
+    let bytes = write_ipc(&mut frame, SCHEMA)?;
+    assert_eq!(read_ipc_schema(&bytes)?, Some(SCHEMA));
+    let loaded = load("CV-15.hmf.ipc", &bytes)?;
+    assert_eq!(loaded.meta.name, frame.meta.name);
+    assert!(loaded.data.equals_missing(&frame.data));
+    let bytes = write_ipc(&mut frame, SCHEMA + 1)?;
+    assert!(load("CV-15.hmf.ipc", &bytes).is_err());
+    Ok(())
+}
@@ -1,3 +1,32 @@
-pub use self::save::save;
+pub use self::{
+    delimited::{parse_number, records, sniff_delimiter, to_delimited},
+    document::{Document, from_document, to_document},
+    doi::doi,
+    fatty_acid::{fatty_acid_series, parse_fatty_acid, resolve_fatty_acid, sample_data_frame},
+    hash::hash_data_frame,
+    ipc::{read_ipc_schema, write_ipc},
+    load::{Format, load},
+    markup::{Markup, Table, escape_html},
+    migration::{SCHEMA, check_schema, migrate, read_columns},
+    save::{export, save},
+    xlsx::{worksheet_name, write_worksheet},
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::{
+    parquet::{read_parquet, write_parquet},
+    save::key_value_metadata,
+};
 
+mod delimited;
+mod document;
+mod doi;
+mod fatty_acid;
+mod hash;
+mod ipc;
+mod load;
+mod markup;
+mod migration;
+#[cfg(not(target_arch = "wasm32"))]
+mod parquet;
 mod save;
+mod xlsx;
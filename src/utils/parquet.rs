@@ -0,0 +1,28 @@
+use polars::{io::parquet::write::KeyValueMetadata, prelude::*};
+use std::io::Cursor;
+
+/// Writes the data frame as Parquet with the key-value metadata
+pub fn write_parquet(
+    data_frame: &mut DataFrame,
+    metadata: Vec<(String, String)>,
+) -> PolarsResult<Vec<u8>> {
+    let mut bytes = Vec::new();
+    ParquetWriter::new(&mut bytes)
+        .with_key_value_metadata(Some(KeyValueMetadata::from_static(metadata)))
+        .finish(data_frame)?;
+    Ok(bytes)
+}
+
+/// Reads the data frame and the key-value metadata of Parquet
+pub fn read_parquet(bytes: &[u8]) -> PolarsResult<(DataFrame, Vec<(String, String)>)> {
+    let mut reader = ParquetReader::new(Cursor::new(bytes));
+    let metadata = reader
+        .get_metadata()?
+        .key_value_metadata()
+        .iter()
+        .flatten()
+        .filter_map(|key_value| Some((key_value.key.clone(), key_value.value.clone()?)))
+        .collect();
+    let data_frame = reader.finish()?;
+    Ok((data_frame, metadata))
+}
@@ -1,20 +1,62 @@
+use super::{Format, SCHEMA, to_document, write_ipc};
+#[cfg(not(target_arch = "wasm32"))]
+use super::{doi, write_parquet};
 use anyhow::Result;
 use metadata::MetaDataFrame;
-use std::fs::File;
+use ron::{extensions::Extensions, ser::PrettyConfig};
+
+/// Saves the frame in the format
+pub fn save(name: &str, frame: &mut MetaDataFrame, format: Format) -> Result<()> {
+    let bytes = match format {
+        Format::Ipc => write_ipc(frame, SCHEMA)?,
+        Format::Ron => ron::ser::to_string_pretty(
+            &to_document(&frame.meta, &frame.data)?,
+            PrettyConfig::default().extensions(Extensions::IMPLICIT_SOME),
+        )?
+        .into_bytes(),
+        Format::Json => {
+            serde_json::to_string_pretty(&to_document(&frame.meta, &frame.data)?)?.into_bytes()
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        Format::Parquet => {
+            let metadata = key_value_metadata(frame);
+            write_parquet(&mut frame.data, metadata)?
+        }
+    };
+    export(name, &bytes)
+}
+
+/// Parquet key-value metadata of the frame: schema, name, version,
+/// description and DOI
+#[cfg(not(target_arch = "wasm32"))]
+pub fn key_value_metadata(frame: &MetaDataFrame) -> Vec<(String, String)> {
+    let mut metadata = vec![
+        ("schema".to_owned(), SCHEMA.to_string()),
+        ("name".to_owned(), frame.meta.name.clone()),
+    ];
+    if let Some(version) = &frame.meta.version {
+        metadata.push(("version".to_owned(), version.to_string()));
+    }
+    if !frame.meta.description.is_empty() {
+        metadata.push(("description".to_owned(), frame.meta.description.clone()));
+    }
+    if let Some(doi) = doi(&frame.meta.description) {
+        metadata.push(("doi".to_owned(), doi.to_owned()));
+    }
+    metadata
+}
 
+/// Writes the bytes to a file, downloads them on the web
 #[cfg(not(target_arch = "wasm32"))]
-pub fn save(name: &str, frame: &mut MetaDataFrame) -> Result<()> {
-    let file = File::create(name)?;
-    MetaDataFrame::new(frame.meta.clone(), &mut frame.data).write(file)?;
+pub fn export(name: &str, bytes: &[u8]) -> Result<()> {
+    std::fs::write(name, bytes)?;
     Ok(())
 }
 
 #[cfg(target_arch = "wasm32")]
-pub fn save(name: &str, frame: &mut MetaDataFrame) -> Result<()> {
+pub fn export(name: &str, bytes: &[u8]) -> Result<()> {
     use anyhow::anyhow;
     use egui_ext::download;
 
-    let mut bytes = Vec::new();
-    MetaDataFrame::new(frame.meta.clone(), &mut frame.data).write(&mut bytes)?;
-    download(name, &bytes).map_err(|error| anyhow!(error))
+    download(name, bytes).map_err(|error| anyhow!(error))
 }
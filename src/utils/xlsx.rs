@@ -0,0 +1,113 @@
+use polars::prelude::*;
+use rust_xlsxwriter::{Format, Worksheet, XlsxError};
+
+const NAME_LEN: usize = 31;
+
+/// Writes the metadata lines and the data frame with a bold header line
+///
+/// Floats keep their values and are displayed with the precision, the header
+/// line is frozen.
+pub fn write_worksheet(
+    worksheet: &mut Worksheet,
+    metadata: &[(&str, String)],
+    data_frame: &DataFrame,
+    precision: usize,
+) -> Result<(), XlsxError> {
+    let bold = Format::new().set_bold();
+    let number = Format::new().set_num_format(if precision == 0 {
+        "0".to_owned()
+    } else {
+        format!("0.{}", "0".repeat(precision))
+    });
+    let mut row = 0;
+    for (key, value) in metadata {
+        worksheet.write_string_with_format(row, 0, *key, &bold)?;
+        worksheet.write_string(row, 1, value)?;
+        row += 1;
+    }
+    if !metadata.is_empty() {
+        row += 1;
+    }
+    for (column, name) in data_frame.get_column_names().into_iter().enumerate() {
+        worksheet.write_string_with_format(row, column as _, name.as_str(), &bold)?;
+    }
+    worksheet.set_freeze_panes(row + 1, 0)?;
+    for index in 0..data_frame.height() {
+        row += 1;
+        for (column, series) in data_frame.get_columns().iter().enumerate() {
+            let column = column as _;
+            match series
+                .get(index)
+                .map_err(|error| XlsxError::ParameterError(error.to_string()))?
+            {
+                AnyValue::Null => {}
+                AnyValue::Float64(value) => {
+                    if value.is_finite() {
+                        worksheet.write_number_with_format(row, column, value, &number)?;
+                    }
+                }
+                AnyValue::Float32(value) => {
+                    if value.is_finite() {
+                        worksheet.write_number_with_format(row, column, value, &number)?;
+                    }
+                }
+                AnyValue::UInt8(value) => {
+                    worksheet.write_number(row, column, value)?;
+                }
+                AnyValue::UInt32(value) => {
+                    worksheet.write_number(row, column, value)?;
+                }
+                AnyValue::UInt64(value) => {
+                    worksheet.write_number(row, column, value as f64)?;
+                }
+                AnyValue::Int32(value) => {
+                    worksheet.write_number(row, column, value)?;
+                }
+                AnyValue::Int64(value) => {
+                    worksheet.write_number(row, column, value as f64)?;
+                }
+                AnyValue::String(value) => {
+                    worksheet.write_string(row, column, value)?;
+                }
+                AnyValue::StringOwned(value) => {
+                    worksheet.write_string(row, column, value.as_str())?;
+                }
+                value => {
+                    worksheet.write_string(row, column, value.to_string())?;
+                }
+            }
+        }
+    }
+    worksheet.autofit();
+    Ok(())
+}
+
+/// Worksheet name: at most 31 characters without `[]:*?/\` and unique among
+/// the used names
+pub fn worksheet_name(name: &str, used: &mut Vec<String>) -> String {
+    let name = name
+        .chars()
+        .map(|char| match char {
+            '[' | ']' | ':' | '*' | '?' | '/' | '\\' => '_',
+            _ => char,
+        })
+        .collect::<String>();
+    let name = name.trim_matches('\'');
+    let name = if name.is_empty() { "Sheet" } else { name };
+    let mut unique = name.chars().take(NAME_LEN).collect::<String>();
+    let mut index = 1;
+    while used
+        .iter()
+        .any(|used| used.to_lowercase() == unique.to_lowercase())
+    {
+        index += 1;
+        let suffix = format!(" ({index})");
+        unique = name
+            .chars()
+            .take(NAME_LEN - suffix.chars().count())
+            .chain(suffix.chars())
+            .collect();
+    }
+    used.push(unique.clone());
+    unique
+}